@@ -0,0 +1,131 @@
+use crate::Error;
+use serde::{Serialize, Serializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Multi-line text, such as letters drawn on a grid, which needs reading
+    /// by eye before it can be submitted.
+    Grid(String),
+}
+
+impl Answer {
+    pub fn is_multi_line(&self) -> bool {
+        match self {
+            Answer::Integer(_) => false,
+            Answer::Text(text) | Answer::Grid(text) => text.contains('\n'),
+        }
+    }
+
+    /// The text to submit for this answer, refusing answers that the server
+    /// could never accept.
+    pub fn submission(&self) -> Result<String, Error> {
+        match self {
+            Answer::Integer(value) => Ok(value.to_string()),
+            Answer::Text(text) if text.trim().is_empty() => Err(Error::Refused(
+                "Refusing to submit an empty answer".to_string(),
+            )),
+            Answer::Text(text) if text.contains('\n') => Err(Error::Refused(
+                "Refusing to submit a multi-line answer".to_string(),
+            )),
+            Answer::Text(text) => Ok(text.trim().to_string()),
+            Answer::Grid(_) => Err(Error::Refused(
+                "Refusing to submit a grid answer, read it and submit the text instead".to_string(),
+            )),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+/// Text answers are classified the same way as parsed ones, so that a solver
+/// returning digits as a string still matches the expected answer.
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        if let Ok(value) = text.trim().parse() {
+            Answer::Integer(value)
+        } else if text.contains('\n') {
+            Answer::Grid(text)
+        } else {
+            Answer::Text(text)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::from(text.to_string())
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Answer::from(s))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) | Answer::Grid(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(value) => serializer.serialize_i128(*value),
+            Answer::Text(text) | Answer::Grid(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("0042".parse::<Answer>().unwrap(), Answer::from(42u64));
+        assert_eq!("-7".parse::<Answer>().unwrap(), Answer::from(-7i64));
+        assert_eq!(
+            "abc".parse::<Answer>().unwrap(),
+            Answer::Text("abc".to_string())
+        );
+        assert!("#.\n.#".parse::<Answer>().unwrap().is_multi_line());
+        assert_eq!(
+            Answer::from("5158916779".to_string()),
+            "5158916779".parse().unwrap()
+        );
+        assert!(Answer::Text("a\nb".to_string()).is_multi_line());
+    }
+
+    #[test]
+    fn test_submission() {
+        assert_eq!(Answer::from(12usize).submission().unwrap(), "12");
+        assert_eq!(Answer::from(" ab ").submission().unwrap(), "ab");
+        assert!(Answer::from("").submission().is_err());
+        assert!(Answer::from("a\nb").submission().is_err());
+        assert!(Answer::Grid("#.\n.#".to_string()).submission().is_err());
+    }
+}
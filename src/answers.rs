@@ -0,0 +1,306 @@
+use crate::{Answer, Error, Part, Solution, run_day};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+mod parse {
+    use crate::parsers::{nom_error, signed, unsigned};
+    use crate::{Answer, Error, Part};
+    use nom::branch::alt;
+    use nom::bytes::complete::{tag, take_till1};
+    use nom::character::complete::{not_line_ending, space1};
+    use nom::combinator::{all_consuming, map, map_res, value};
+    use nom::sequence::terminated;
+    use nom::{IResult, Parser};
+    use std::path::{Path, PathBuf};
+
+    use super::Expected;
+
+    fn part(input: &str) -> IResult<&str, Part> {
+        alt((value(Part::One, tag("one")), value(Part::Two, tag("two")))).parse(input)
+    }
+
+    fn path(input: &str) -> IResult<&str, PathBuf> {
+        map(take_till1(|c: char| c.is_whitespace()), PathBuf::from).parse(input)
+    }
+
+    fn expected(input: &str) -> IResult<&str, Expected> {
+        map(
+            (
+                terminated(signed, space1),
+                terminated(unsigned, space1),
+                terminated(part, space1),
+                terminated(path, space1),
+                answer,
+            ),
+            |(year, day, part, input, answer)| Expected {
+                year: year as i32,
+                day,
+                part,
+                input,
+                answer,
+            },
+        )
+        .parse(input)
+    }
+
+    fn answer(input: &str) -> IResult<&str, Answer> {
+        map_res(not_line_ending, |answer: &str| answer.trim_end().parse()).parse(input)
+    }
+
+    fn example_answer(input: &str) -> IResult<&str, (Part, Answer)> {
+        (terminated(part, space1), answer).parse(input)
+    }
+
+    fn parse_lines<'a, T>(
+        data: &'a str,
+        parser: impl Fn(&'a str) -> IResult<&'a str, T>,
+    ) -> Result<Vec<T>, Error> {
+        data.lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                all_consuming(&parser)
+                    .parse(line)
+                    .map(|(_, item)| item)
+                    .map_err(|err| nom_error(data, err))
+            })
+            .collect()
+    }
+
+    pub(super) fn parse_answers(data: &str) -> Result<Vec<Expected>, Error> {
+        parse_lines(data, expected)
+    }
+
+    pub(super) fn parse_part_answers(data: &str) -> Result<Vec<(Part, Answer)>, Error> {
+        parse_lines(data, example_answer)
+    }
+
+    pub(super) fn parse_example_answers(
+        data: &str,
+        year: i32,
+        day: u32,
+        input: &Path,
+    ) -> Result<Vec<Expected>, Error> {
+        Ok(parse_part_answers(data)?
+            .into_iter()
+            .map(|(part, answer)| Expected {
+                year,
+                day,
+                part,
+                input: input.to_path_buf(),
+                answer,
+            })
+            .collect())
+    }
+}
+
+/// An expected answer for one part of a day, run against a specific input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub year: i32,
+    pub day: u32,
+    pub part: Part,
+    pub input: PathBuf,
+    pub answer: Answer,
+}
+
+pub enum Outcome {
+    Correct,
+    Incorrect {
+        expected: Answer,
+        actual: Option<Answer>,
+    },
+    Failed(String),
+}
+
+impl Outcome {
+    pub fn is_correct(&self) -> bool {
+        matches!(self, Outcome::Correct)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "ok"),
+            Outcome::Incorrect {
+                expected,
+                actual: Some(actual),
+            } => write!(f, "expected {}, got {}", expected, actual),
+            Outcome::Incorrect {
+                expected,
+                actual: None,
+            } => write!(f, "expected {}, got no answer", expected),
+            Outcome::Failed(err) => write!(f, "error: {}", err),
+        }
+    }
+}
+
+pub fn read_answers<P: AsRef<Path>>(path: P) -> Result<Vec<Expected>, Error> {
+    let data = read_to_string(&path).map_err(|err| Error::io(&path, err))?;
+    parse::parse_answers(&data).map_err(|err| err.in_file(&path))
+}
+
+/// Parse `<part> <answer>` lines, as used for example answers.
+pub(crate) fn parse_part_answers(data: &str) -> Result<Vec<(Part, Answer)>, Error> {
+    parse::parse_part_answers(data)
+}
+
+/// Collect the expected answers for the puzzle examples under `dir`.
+///
+/// Each `<year>/dayNN` directory holds fixtures named `<name>.txt`, with the
+/// expected answers for each alongside in `<name>.answers` as
+/// `<part> <answer>` lines.
+pub fn read_examples<P: AsRef<Path>>(dir: P) -> Result<Vec<Expected>, Error> {
+    let mut expected = vec![];
+
+    for year_dir in sorted_entries(dir.as_ref())? {
+        let Some(year) = year_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|year| year.parse().ok())
+        else {
+            continue;
+        };
+
+        for day_dir in sorted_entries(&year_dir)? {
+            let Some(day) = day_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix("day"))
+                .and_then(|day| day.parse().ok())
+            else {
+                continue;
+            };
+
+            for fixture in sorted_entries(&day_dir)? {
+                if fixture
+                    .extension()
+                    .is_none_or(|extension| extension != "txt")
+                {
+                    continue;
+                }
+
+                let answers = fixture.with_extension("answers");
+                let data = read_to_string(&answers).map_err(|err| Error::io(&answers, err))?;
+                expected.extend(
+                    parse::parse_example_answers(&data, year, day, &fixture)
+                        .map_err(|err| err.in_file(&answers))?,
+                );
+            }
+        }
+    }
+
+    Ok(expected)
+}
+
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut entries = read_dir(dir)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(|err| Error::io(dir, err))?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// Check each expected answer, solving each input only once however many
+/// answers refer to it.
+pub fn verify<F>(expected: &[Expected], mut read: F) -> Vec<(&Expected, Outcome)>
+where
+    F: FnMut(&Path) -> Result<String, Error>,
+{
+    let mut solutions: HashMap<(i32, u32, &Path), Result<Solution, String>> = HashMap::new();
+
+    expected
+        .iter()
+        .map(|expected| {
+            let solution = solutions
+                .entry((expected.year, expected.day, &expected.input))
+                .or_insert_with(|| {
+                    read(&expected.input)
+                        .and_then(|data| run_day(expected.year, expected.day, data, None))
+                        .map_err(|err| err.to_string())
+                });
+
+            let outcome = match solution {
+                Ok(solution) => {
+                    let actual = match expected.part {
+                        Part::One => &solution.part_one,
+                        Part::Two => &solution.part_two,
+                    };
+                    if actual.as_ref() == Some(&expected.answer) {
+                        Outcome::Correct
+                    } else {
+                        Outcome::Incorrect {
+                            expected: expected.answer.clone(),
+                            actual: actual.clone(),
+                        }
+                    }
+                }
+                Err(err) => Outcome::Failed(err.clone()),
+            };
+
+            (expected, outcome)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DAY_ONE_EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    #[test]
+    fn test_verify() {
+        let expected = parse::parse_answers(
+            "# year day part input answer\n\
+             2025 1 one example.txt 3\n\
+             2025 1 two example.txt 7\n\
+             2025 1 one missing.txt 3\n",
+        )
+        .unwrap();
+
+        let mut reads = 0;
+        let outcomes = verify(&expected, |path| {
+            reads += 1;
+            if path == Path::new("example.txt") {
+                Ok(DAY_ONE_EXAMPLE.to_string())
+            } else {
+                Err(Error::invalid("not found"))
+            }
+        });
+
+        assert_eq!(reads, 2);
+        assert!(outcomes[0].1.is_correct());
+        assert_eq!(outcomes[1].1.to_string(), "expected 7, got 6");
+        assert!(matches!(outcomes[2].1, Outcome::Failed(_)));
+    }
+
+    #[test]
+    fn test_answers_file() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let expected: Vec<_> = read_answers(root.join("answers.txt"))
+            .unwrap()
+            .into_iter()
+            .filter(|expected| root.join(&expected.input).exists())
+            .collect();
+        assert!(!expected.is_empty(), "answers.txt has nothing to check");
+
+        for (expected, outcome) in verify(&expected, |path| Ok(read_to_string(root.join(path))?)) {
+            assert!(
+                outcome.is_correct(),
+                "{} day {} part {} ({}): {}",
+                expected.year,
+                expected.day,
+                expected.part,
+                expected.input.display(),
+                outcome
+            );
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::Error;
+use crate::answers::parse_part_answers;
+use crate::{Answer, Part};
+use aocf::{Aoc, Level};
+use std::fs::read_to_string;
+use std::io::{IsTerminal, stdin};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Somewhere to fetch puzzle inputs from.
+pub trait InputSource {
+    fn fetch(&mut self, year: i32, day: u32) -> Result<String, Error>;
+}
+
+/// Somewhere to submit answers to, returning the response text.
+pub trait Submitter {
+    fn submit(&mut self, year: i32, day: u32, part: Part, answer: &str) -> Result<String, Error>;
+}
+
+pub fn init_aoc(year: i32, day: u32) -> Result<Aoc, Error> {
+    Ok(Aoc::new()
+        .parse_cli(false)
+        .year(Some(year))
+        .day(Some(day))
+        .init()?)
+}
+
+/// The Advent of Code server, via `aocf` and its session cookie.
+pub struct AocServer;
+
+impl InputSource for AocServer {
+    fn fetch(&mut self, year: i32, day: u32) -> Result<String, Error> {
+        // `aocf` reads the input from standard input rather than the server
+        // whenever it isn't a terminal, and that mustn't end up in the cache.
+        if !stdin().is_terminal() {
+            return Err(Error::Network(
+                "Can't fetch inputs unless standard input is a terminal, as aocf would read it \
+                 instead of the server"
+                    .to_string(),
+            ));
+        }
+
+        Ok(init_aoc(year, day)?.get_input(false)?)
+    }
+}
+
+impl Submitter for AocServer {
+    fn submit(&mut self, year: i32, day: u32, part: Part, answer: &str) -> Result<String, Error> {
+        let mut aoc = init_aoc(year, day)?;
+        aoc.level = match part {
+            Part::One => Level::First,
+            Part::Two => Level::Second,
+        };
+        Ok(aoc.submit(answer)?)
+    }
+}
+
+/// A stand-in for the server backed by a directory laid out like the input
+/// cache, with `dayNN.txt` inputs and `dayNN.answers` holding the correct
+/// answers as `<part> <answer>` lines.
+///
+/// Responses use the same wording as the real server, and any submission
+/// within `cooldown` of a wrong answer is rate limited.
+pub struct MockServer {
+    dir: PathBuf,
+    cooldown: Duration,
+    locked_until: Option<Instant>,
+    pub fetches: Vec<(i32, u32)>,
+    pub submissions: Vec<(i32, u32, Part, String)>,
+}
+
+impl MockServer {
+    pub fn new<P: AsRef<Path>>(dir: P, cooldown: Duration) -> Self {
+        MockServer {
+            dir: dir.as_ref().to_path_buf(),
+            cooldown,
+            locked_until: None,
+            fetches: vec![],
+            submissions: vec![],
+        }
+    }
+
+    fn path(&self, year: i32, day: u32, extension: &str) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.{}", day, extension))
+    }
+
+    fn correct_answer(&self, year: i32, day: u32, part: Part) -> Result<Answer, Error> {
+        let path = self.path(year, day, "answers");
+        let data = read_to_string(&path).map_err(|err| Error::io(&path, err))?;
+        parse_part_answers(&data)
+            .map_err(|err| err.in_file(&path))?
+            .into_iter()
+            .find(|(answer_part, _)| *answer_part == part)
+            .map(|(_, answer)| answer)
+            .ok_or_else(|| Error::invalid(format!("No answer for day {} part {}", day, part)))
+    }
+}
+
+impl InputSource for MockServer {
+    fn fetch(&mut self, year: i32, day: u32) -> Result<String, Error> {
+        self.fetches.push((year, day));
+        let path = self.path(year, day, "txt");
+        read_to_string(&path).map_err(|err| Error::Network(format!("{}: {}", path.display(), err)))
+    }
+}
+
+impl Submitter for MockServer {
+    fn submit(&mut self, year: i32, day: u32, part: Part, answer: &str) -> Result<String, Error> {
+        self.submissions.push((year, day, part, answer.to_string()));
+
+        let now = Instant::now();
+        if let Some(until) = self.locked_until.filter(|&until| now < until) {
+            return Ok(format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have {}s left to wait.",
+                (until - now).as_secs()
+            ));
+        }
+
+        let correct = self.correct_answer(year, day, part)?;
+        if answer.parse::<Answer>()? == correct {
+            self.locked_until = None;
+            return Ok("That's the right answer! You are one gold star closer.".to_string());
+        }
+
+        self.locked_until = Some(now + self.cooldown);
+        let hint = match (answer.parse::<i128>(), &correct) {
+            (Ok(value), Answer::Integer(correct)) if value > *correct => {
+                "; your answer is too high."
+            }
+            (Ok(value), Answer::Integer(correct)) if value < *correct => {
+                "; your answer is too low."
+            }
+            _ => ".",
+        };
+        Ok(format!("That's not the right answer{}", hint))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{InputCache, Ledger, Verdict, read_input, run_day, submit};
+    use std::fs::{create_dir_all, write};
+    use tempfile::{TempDir, tempdir};
+
+    const DAY_ONE_EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    fn mock_server(answers: &str, cooldown: Duration) -> (TempDir, MockServer) {
+        let dir = tempdir().unwrap();
+        let year = dir.path().join("2025");
+        create_dir_all(&year).unwrap();
+        write(year.join("day01.txt"), DAY_ONE_EXAMPLE).unwrap();
+        write(year.join("day01.answers"), answers).unwrap();
+        let server = MockServer::new(dir.path(), cooldown);
+        (dir, server)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (_server_dir, mut server) = mock_server("one 3\n", Duration::ZERO);
+        let cache_dir = tempdir().unwrap();
+        let cache = InputCache::new(cache_dir.path());
+
+        for _ in 0..2 {
+            let data =
+                read_input(None::<&Path>, &cache, &mut server, 2025, 1, false, true).unwrap();
+            assert_eq!(data, DAY_ONE_EXAMPLE);
+        }
+        assert_eq!(server.fetches, vec![(2025, 1)]);
+
+        assert!(read_input(None::<&Path>, &cache, &mut server, 2025, 2, false, true).is_err());
+        assert!(read_input(None::<&Path>, &cache, &mut server, 2025, 3, true, true).is_err());
+        assert_eq!(server.fetches, vec![(2025, 1), (2025, 2)]);
+    }
+
+    #[test]
+    fn test_empty_fetch() {
+        let (server_dir, mut server) = mock_server("one 3\n", Duration::ZERO);
+        write(server_dir.path().join("2025").join("day02.txt"), "").unwrap();
+        let cache_dir = tempdir().unwrap();
+        let cache = InputCache::new(cache_dir.path());
+
+        let err = read_input(None::<&Path>, &cache, &mut server, 2025, 2, false, true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Refusing to cache an empty input for day 2"
+        );
+        assert_eq!(cache.get(2025, 2).unwrap(), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        // The example's answers are 3 and 6, so claim otherwise to get
+        // wrong-answer responses.
+        let (_server_dir, mut server) = mock_server("one 3\ntwo 5\n", Duration::ZERO);
+        let ledger_dir = tempdir().unwrap();
+        let mut ledger = Ledger::load(ledger_dir.path().join("submissions.json")).unwrap();
+        let solution = run_day(2025, 1, DAY_ONE_EXAMPLE.to_string(), None).unwrap();
+
+        let response = submit(2025, 1, Part::One, &solution, &mut ledger, &mut server).unwrap();
+        assert_eq!(Verdict::from_response(&response), Verdict::Correct);
+        assert!(submit(2025, 1, Part::One, &solution, &mut ledger, &mut server).is_err());
+
+        let response = submit(2025, 1, Part::Two, &solution, &mut ledger, &mut server).unwrap();
+        assert_eq!(Verdict::from_response(&response), Verdict::TooHigh);
+        assert!(submit(2025, 1, Part::Two, &solution, &mut ledger, &mut server).is_err());
+
+        assert_eq!(server.submissions.len(), 2);
+        let verdicts: Vec<_> = Ledger::load(ledger_dir.path().join("submissions.json"))
+            .unwrap()
+            .history(1)
+            .map(|submission| (submission.part, submission.verdict))
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![(Part::One, Verdict::Correct), (Part::Two, Verdict::TooHigh)]
+        );
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let (_server_dir, mut server) = mock_server("one 4\n", Duration::from_secs(60));
+        let ledger_dir = tempdir().unwrap();
+        let mut ledger = Ledger::load(ledger_dir.path().join("submissions.json")).unwrap();
+        let solution = run_day(2025, 1, DAY_ONE_EXAMPLE.to_string(), None).unwrap();
+
+        let response = submit(2025, 1, Part::One, &solution, &mut ledger, &mut server).unwrap();
+        assert_eq!(Verdict::from_response(&response), Verdict::TooLow);
+
+        let response = server.submit(2025, 1, Part::One, "4").unwrap();
+        assert_eq!(Verdict::from_response(&response), Verdict::Wait);
+        let wait: u64 = response
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once("s left"))
+            .and_then(|(seconds, _)| seconds.parse().ok())
+            .unwrap();
+        assert!((1..=60).contains(&wait), "waiting {}s", wait);
+    }
+}
@@ -0,0 +1,222 @@
+use crate::Error;
+use crate::output::csv_row;
+use crate::{Output, Part, format_duration, run_day};
+use serde::{Serialize, Serializer};
+use serde_json::json;
+use std::fmt::Display;
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// Summary statistics over the timings of repeated runs of one phase.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+    #[serde(rename = "stddev_ns", serialize_with = "as_nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / len as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Stats {
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {}, median {}, mean {}, stddev {}",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.mean),
+            format_duration(self.stddev)
+        )
+    }
+}
+
+/// Timing statistics for each phase of a day; a part that wasn't run, or
+/// that was solved together with the other, has no statistics of its own.
+#[derive(Debug, Clone, Serialize)]
+pub struct Benchmark {
+    pub day: u32,
+    pub runs: u32,
+    pub parse: Stats,
+    pub part_one: Option<Stats>,
+    pub part_two: Option<Stats>,
+    pub solve: Stats,
+}
+
+impl Display for Benchmark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} ({} runs)", self.day, self.runs)?;
+        write!(f, "\n  Parse: {}", self.parse)?;
+        if let Some(stats) = &self.part_one {
+            write!(f, "\n  Part 1: {}", stats)?;
+        }
+        if let Some(stats) = &self.part_two {
+            write!(f, "\n  Part 2: {}", stats)?;
+        }
+        write!(f, "\n  Solve: {}", self.solve)
+    }
+}
+
+/// Parse and solve the input `runs` times, each from a fresh copy of the data.
+pub fn bench_day(
+    year: i32,
+    day: u32,
+    data: &str,
+    part: Option<Part>,
+    runs: NonZeroU32,
+) -> Result<Benchmark, Error> {
+    let mut parse_times = vec![];
+    let mut part_one_times = vec![];
+    let mut part_two_times = vec![];
+    let mut solve_times = vec![];
+
+    for _ in 0..runs.get() {
+        let solution = run_day(year, day, data.to_string(), part)?;
+        parse_times.push(solution.parse_time);
+        part_one_times.push(solution.part_one_time);
+        part_two_times.push(solution.part_two_time);
+        solve_times.push(solution.solve_time);
+    }
+
+    let stats = |times: Vec<Option<Duration>>| {
+        times
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .map(Stats::from_samples)
+    };
+
+    Ok(Benchmark {
+        day,
+        runs: runs.get(),
+        parse: Stats::from_samples(parse_times),
+        part_one: stats(part_one_times),
+        part_two: stats(part_two_times),
+        solve: Stats::from_samples(solve_times),
+    })
+}
+
+impl Stats {
+    fn csv_fields(stats: Option<&Self>) -> [String; 4] {
+        match stats {
+            Some(stats) => [stats.min, stats.median, stats.mean, stats.stddev]
+                .map(|time| time.as_nanos().to_string()),
+            None => Default::default(),
+        }
+    }
+}
+
+pub fn display_benchmarks(
+    results: &[(u32, Result<Benchmark, Error>)],
+    output: Output,
+) -> Result<(), Error> {
+    match output {
+        Output::Text => {
+            for (day, result) in results {
+                match result {
+                    Ok(benchmark) => println!("{}", benchmark),
+                    Err(err) => println!("Day {}\n  Error: {}", day, err),
+                }
+            }
+        }
+        Output::Json => {
+            let records = results
+                .iter()
+                .map(|(day, result)| match result {
+                    Ok(benchmark) => serde_json::to_value(benchmark),
+                    Err(err) => Ok(json!({ "day": day, "error": err.to_string() })),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            println!("{}", serde_json::to_string_pretty(&records)?);
+        }
+        Output::Csv => {
+            let mut header = vec!["day".to_string(), "runs".to_string()];
+            for phase in ["parse", "part_one", "part_two", "solve"] {
+                for stat in ["min", "median", "mean", "stddev"] {
+                    header.push(format!("{}_{}_ns", phase, stat));
+                }
+            }
+            header.push("error".to_string());
+            println!("{}", csv_row(&header));
+
+            for (day, result) in results {
+                let mut row = vec![day.to_string()];
+                match result {
+                    Ok(benchmark) => {
+                        row.push(benchmark.runs.to_string());
+                        row.extend(Stats::csv_fields(Some(&benchmark.parse)));
+                        row.extend(Stats::csv_fields(benchmark.part_one.as_ref()));
+                        row.extend(Stats::csv_fields(benchmark.part_two.as_ref()));
+                        row.extend(Stats::csv_fields(Some(&benchmark.solve)));
+                        row.push(String::new());
+                    }
+                    Err(err) => {
+                        row.extend((0..17).map(|_| String::new()));
+                        row.push(err.to_string());
+                    }
+                }
+                println!("{}", csv_row(&row));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(
+            [4, 1, 3, 2]
+                .into_iter()
+                .map(Duration::from_micros)
+                .collect(),
+        );
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(
+            stats.median,
+            Duration::from_micros(2) + Duration::from_nanos(500)
+        );
+        assert_eq!(
+            stats.mean,
+            Duration::from_micros(2) + Duration::from_nanos(500)
+        );
+        assert_eq!(stats.stddev.as_nanos(), 1118);
+    }
+}
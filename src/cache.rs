@@ -0,0 +1,177 @@
+use crate::Error;
+use std::fs::{create_dir_all, read_dir, read_to_string, remove_file, write};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// A local directory of puzzle inputs, laid out as `<dir>/<year>/dayNN.txt`.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        InputCache {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, year: i32, day: u32) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// Where the submission ledger for the year is kept.
+    pub fn ledger_path(&self, year: i32) -> PathBuf {
+        self.dir.join(year.to_string()).join("submissions.json")
+    }
+
+    pub fn get(&self, year: i32, day: u32) -> Result<Option<String>, Error> {
+        let path = self.path(year, day);
+        match read_to_string(&path) {
+            Ok(data) => Ok(Some(data)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(Error::io(path, err)),
+        }
+    }
+
+    pub fn store(&self, year: i32, day: u32, data: &str) -> Result<(), Error> {
+        if data.trim().is_empty() {
+            return Err(Error::Invalid(format!(
+                "Refusing to cache an empty input for day {}",
+                day
+            )));
+        }
+
+        let path = self.path(year, day);
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent).map_err(|err| Error::io(parent, err))?;
+        }
+        write(&path, data).map_err(|err| Error::io(path, err))
+    }
+
+    /// The days with a cached input for the year, in order.
+    pub fn list(&self, year: i32) -> Result<Vec<u32>, Error> {
+        let dir = self.dir.join(year.to_string());
+        let entries = match read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(Error::io(dir, err)),
+        };
+
+        let mut days = vec![];
+        for entry in entries {
+            let name = entry?.file_name();
+            if let Some(day) = name
+                .to_str()
+                .and_then(|name| name.strip_prefix("day"))
+                .and_then(|name| name.strip_suffix(".txt"))
+                .and_then(|day| day.parse().ok())
+            {
+                days.push(day);
+            }
+        }
+
+        days.sort();
+        Ok(days)
+    }
+
+    /// Remove the cached input for a day, returning whether there was one.
+    pub fn remove(&self, year: i32, day: u32) -> Result<bool, Error> {
+        let path = self.path(year, day);
+        match remove_file(&path) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(Error::io(path, err)),
+        }
+    }
+
+    /// Read a day's input from the cache, only falling back to `fetch` (and
+    /// caching the result) when not `offline`.
+    pub fn read<F>(&self, year: i32, day: u32, offline: bool, fetch: F) -> Result<String, Error>
+    where
+        F: FnOnce() -> Result<String, Error>,
+    {
+        if let Some(data) = self.get(year, day)? {
+            return Ok(data);
+        }
+
+        if offline {
+            return Err(Error::Invalid(format!(
+                "No cached input for day {} at {}, run `fetch` first or drop --offline",
+                day,
+                self.path(year, day).display()
+            )));
+        }
+
+        let data = fetch()?;
+        self.store(year, day, &data)?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_store_and_list() {
+        let dir = tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+
+        assert!(cache.list(2025).unwrap().is_empty());
+        assert_eq!(cache.get(2025, 3).unwrap(), None);
+
+        cache.store(2025, 3, "three\n").unwrap();
+        cache.store(2025, 12, "twelve\n").unwrap();
+        cache.store(2024, 1, "one\n").unwrap();
+
+        assert_eq!(cache.get(2025, 3).unwrap().as_deref(), Some("three\n"));
+        assert_eq!(cache.list(2025).unwrap(), vec![3, 12]);
+        assert!(dir.path().join("2025").join("day03.txt").exists());
+
+        assert!(cache.remove(2025, 3).unwrap());
+        assert!(!cache.remove(2025, 3).unwrap());
+        assert_eq!(cache.list(2025).unwrap(), vec![12]);
+        assert_eq!(cache.list(2024).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_read_offline() {
+        let dir = tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+
+        let err = cache
+            .read(2025, 1, true, || panic!("fetched while offline"))
+            .unwrap_err();
+        assert!(err.to_string().contains("No cached input for day 1"));
+
+        cache.store(2025, 1, "cached\n").unwrap();
+        let data = cache
+            .read(2025, 1, true, || panic!("fetched while offline"))
+            .unwrap();
+        assert_eq!(data, "cached\n");
+    }
+
+    #[test]
+    fn test_read_fetches_once() {
+        let dir = tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+
+        let data = cache
+            .read(2025, 2, false, || Ok("fetched\n".to_string()))
+            .unwrap();
+        assert_eq!(data, "fetched\n");
+        assert_eq!(cache.get(2025, 2).unwrap().as_deref(), Some("fetched\n"));
+
+        let data = cache
+            .read(2025, 2, false, || panic!("fetched twice"))
+            .unwrap();
+        assert_eq!(data, "fetched\n");
+    }
+}
@@ -1,22 +1,247 @@
 #![allow(unused)]
 
-use failure::{Error, err_msg};
-use itertools::iproduct;
+use crate::Error;
+use itertools::{Itertools, iproduct};
+use num::PrimInt;
 use num::rational::Ratio;
 use std::{
+    cell::Cell,
     cmp::{max, min},
     collections::{HashMap, HashSet},
     fmt::Display,
     hash::Hash,
-    ops::{Add, Div, Mul, Sub},
+    ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub},
+    str::FromStr,
 };
 
+/// A point or vector in two dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct Position {
-    pub x: i64,
-    pub y: i64,
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
 }
 
+/// A point or vector in three dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// A point or vector in any number of dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PointN<T, const N: usize>(pub [T; N]);
+
+pub type Position = Point2<i64>;
+
+fn coord_distance<T: PrimInt>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+impl<T: PrimInt, const N: usize> PointN<T, N> {
+    pub fn manhattan_distance(self, other: Self) -> T {
+        self.0
+            .iter()
+            .zip(other.0)
+            .fold(T::zero(), |sum, (&a, b)| sum + coord_distance(a, b))
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        self.0
+            .iter()
+            .zip(other.0)
+            .fold(T::zero(), |furthest, (&a, b)| {
+                furthest.max(coord_distance(a, b))
+            })
+    }
+
+    pub fn squared_distance(self, other: Self) -> T {
+        self.0.iter().zip(other.0).fold(T::zero(), |sum, (&a, b)| {
+            let distance = coord_distance(a, b);
+            sum + distance * distance
+        })
+    }
+
+    /// The points one step along each axis, skipping any that would
+    /// overflow the coordinate type.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |axis| {
+            let coord = self.0[axis];
+            [coord.checked_add(&T::one()), coord.checked_sub(&T::one())]
+                .into_iter()
+                .flatten()
+                .map(move |moved| {
+                    let mut coords = self.0;
+                    coords[axis] = moved;
+                    PointN(coords)
+                })
+        })
+    }
+
+    /// The points one step along any combination of axes, including
+    /// diagonals, skipping any that would overflow the coordinate type.
+    pub fn all_neighbours(self) -> impl Iterator<Item = Self> {
+        (0..3_usize.pow(N as u32)).filter_map(move |mut offsets| {
+            let mut coords = self.0;
+            let mut moved = false;
+            for coord in coords.iter_mut() {
+                *coord = match offsets % 3 {
+                    0 => coord.checked_sub(&T::one())?,
+                    1 => *coord,
+                    _ => coord.checked_add(&T::one())?,
+                };
+                moved |= offsets % 3 != 1;
+                offsets /= 3;
+            }
+            moved.then_some(PointN(coords))
+        })
+    }
+}
+
+impl<T: PrimInt, const N: usize> Add for PointN<T, N> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a = *a + b);
+        self
+    }
+}
+
+impl<T: PrimInt, const N: usize> Sub for PointN<T, N> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a = *a - b);
+        self
+    }
+}
+
+impl<T: PrimInt, const N: usize> Mul<T> for PointN<T, N> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        PointN(self.0.map(|a| a * rhs))
+    }
+}
+
+impl<T: PrimInt, const N: usize> Div<T> for PointN<T, N> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output {
+        PointN(self.0.map(|a| a / rhs))
+    }
+}
+
+impl<T: PrimInt + Neg<Output = T>, const N: usize> Neg for PointN<T, N> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        PointN(self.0.map(|a| -a))
+    }
+}
+
+impl<T: Display, const N: usize> Display for PointN<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.0.iter().join(", "))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for PointN<T, N> {
+    fn from(coords: [T; N]) -> Self {
+        PointN(coords)
+    }
+}
+
+/// Implements the `PointN` operations for a point type with named fields by
+/// converting to and from an array of its coordinates.
+macro_rules! named_point {
+    ($point:ident, $dims:literal, $($field:ident),+) => {
+        impl<T: PrimInt> $point<T> {
+            pub fn to_array(self) -> [T; $dims] {
+                [$(self.$field),+]
+            }
+
+            pub fn from_array([$($field),+]: [T; $dims]) -> Self {
+                $point { $($field),+ }
+            }
+
+            pub fn manhattan_distance(self, other: Self) -> T {
+                PointN::from(self).manhattan_distance(other.into())
+            }
+
+            pub fn chebyshev_distance(self, other: Self) -> T {
+                PointN::from(self).chebyshev_distance(other.into())
+            }
+
+            pub fn squared_distance(self, other: Self) -> T {
+                PointN::from(self).squared_distance(other.into())
+            }
+
+            /// See [`PointN::neighbours`].
+            pub fn neighbours(self) -> impl Iterator<Item = Self> {
+                PointN::from(self).neighbours().map(Self::from)
+            }
+
+            /// See [`PointN::all_neighbours`].
+            pub fn all_neighbours(self) -> impl Iterator<Item = Self> {
+                PointN::from(self).all_neighbours().map(Self::from)
+            }
+        }
+
+        impl<T: PrimInt> From<$point<T>> for PointN<T, $dims> {
+            fn from(point: $point<T>) -> Self {
+                PointN(point.to_array())
+            }
+        }
+
+        impl<T: PrimInt> From<PointN<T, $dims>> for $point<T> {
+            fn from(point: PointN<T, $dims>) -> Self {
+                $point::from_array(point.0)
+            }
+        }
+
+        impl<T: PrimInt> Add for $point<T> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self::Output {
+                $point { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: PrimInt> Sub for $point<T> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self::Output {
+                $point { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: PrimInt> Mul<T> for $point<T> {
+            type Output = Self;
+            fn mul(self, rhs: T) -> Self::Output {
+                $point { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<T: PrimInt> Div<T> for $point<T> {
+            type Output = Self;
+            fn div(self, rhs: T) -> Self::Output {
+                $point { $($field: self.$field / rhs),+ }
+            }
+        }
+
+        impl<T: PrimInt + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Display> Display for $point<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "({})", [$(&self.$field),+].iter().join(", "))
+            }
+        }
+    };
+}
+
+named_point!(Point2, 2, x, y);
+named_point!(Point3, 3, x, y, z);
+
 impl Position {
     pub fn reflect_x(&self, x: i64) -> Position {
         Position {
@@ -55,18 +280,30 @@ impl Position {
         })
     }
 
-    pub fn direction_to(self, other: Self) -> Direction {
-        match (other.x - self.x, other.y - self.y) {
+    /// The octant `other` is in, from the signs of the differences in `x`
+    /// and `y`, so only exactly horizontal or vertical offsets give a
+    /// cardinal direction. `None` if they're the same position.
+    pub fn direction_to(self, other: Self) -> Option<Direction> {
+        self.direction_to_with(other, YAxis::Up)
+    }
+
+    pub fn direction_to_with(self, other: Self, y_axis: YAxis) -> Option<Direction> {
+        let dy = match y_axis {
+            YAxis::Up => other.y - self.y,
+            YAxis::Down => self.y - other.y,
+        };
+        let direction = match (other.x - self.x, dy) {
+            (0, 0) => return None,
             (0, dy) if dy > 0 => Direction::North,
             (dx, dy) if dx > 0 && dy > 0 => Direction::NorthEast,
             (dx, 0) if dx > 0 => Direction::East,
             (dx, dy) if dx > 0 && dy < 0 => Direction::SouthEast,
-            (0, dy) if dy < 0 => Direction::South,
+            (0, _) => Direction::South,
             (dx, dy) if dx < 0 && dy < 0 => Direction::SouthWest,
-            (dx, 0) if dx < 0 => Direction::West,
-            (dx, dy) if dx < 0 && dy > 0 => Direction::NorthWest,
-            _ => unreachable!(),
-        }
+            (_, 0) => Direction::West,
+            _ => Direction::NorthWest,
+        };
+        Some(direction)
     }
 
     pub fn within_range(self, distance: i64) -> impl Iterator<Item = Position> {
@@ -87,20 +324,78 @@ impl Position {
         self.x.abs() + self.y.abs()
     }
 
-    pub fn points_to(self, other: Position) -> impl Iterator<Item = Position> {
+    /// The positions on the line to `other`, excluding `other`, or `None` if
+    /// the line isn't horizontal, vertical or a 45° diagonal.
+    pub fn points_to(self, other: Position) -> Option<impl Iterator<Item = Position>> {
+        let (delta, steps) = self.straight_line_to(other)?;
+        Some((0..steps).map(move |index| self + delta * index))
+    }
+
+    /// Like `points_to`, but including `other`.
+    pub fn points_to_inclusive(self, other: Position) -> Option<impl Iterator<Item = Position>> {
+        let (delta, steps) = self.straight_line_to(other)?;
+        Some((0..=steps).map(move |index| self + delta * index))
+    }
+
+    fn straight_line_to(self, other: Position) -> Option<(Position, i64)> {
         let diff = other - self;
-        assert!(diff.x == 0 || diff.y == 0);
-        let distance = diff.length();
-        let delta = diff / distance;
-        (0..distance).map(move |index| self + delta * index)
+        if diff.x != 0 && diff.y != 0 && diff.x.abs() != diff.y.abs() {
+            return None;
+        }
+        let delta = Position {
+            x: diff.x.signum(),
+            y: diff.y.signum(),
+        };
+        Some((delta, max(diff.x.abs(), diff.y.abs())))
+    }
+
+    /// The positions on the line to `other` at any slope, using Bresenham's
+    /// algorithm, including `other`. Straight lines match `points_to`.
+    pub fn bresenham_to(self, other: Position) -> impl Iterator<Item = Position> {
+        let dx = (other.x - self.x).abs();
+        let dy = -(other.y - self.y).abs();
+        let step = Position {
+            x: (other.x - self.x).signum(),
+            y: (other.y - self.y).signum(),
+        };
+        let mut pos = self;
+        let mut err = dx + dy;
+
+        (0..=max(dx, -dy)).map(move |_| {
+            let current = pos;
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                pos.x += step.x;
+            }
+            if e2 <= dx {
+                err += dx;
+                pos.y += step.y;
+            }
+            current
+        })
+    }
+
+    /// Like `bresenham_to`, but excluding `other`.
+    pub fn bresenham_to_exclusive(self, other: Position) -> impl Iterator<Item = Position> {
+        let steps = max(self.x.abs_diff(other.x), self.y.abs_diff(other.y)) as usize;
+        self.bresenham_to(other).take(steps)
     }
 
     pub fn step(self, direction: Direction) -> Self {
-        self + direction.offset()
+        self.step_with(direction, YAxis::Up)
+    }
+
+    pub fn step_with(self, direction: Direction, y_axis: YAxis) -> Self {
+        self + direction.offset_with(y_axis)
     }
 
     pub fn step_by(self, direction: Direction, len: u32) -> Self {
-        self + direction.offset() * len as i64
+        self.step_by_with(direction, len, YAxis::Up)
+    }
+
+    pub fn step_by_with(self, direction: Direction, len: u32, y_axis: YAxis) -> Self {
+        self + direction.offset_with(y_axis) * len as i64
     }
 
     pub fn origin() -> Self {
@@ -123,50 +418,14 @@ impl From<(usize, usize)> for Position {
     }
 }
 
-impl Add for Position {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        Position {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
-
-impl Sub for Position {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Position {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
-    }
-}
-
-impl Div<i64> for Position {
-    type Output = Self;
-    fn div(self, rhs: i64) -> Self::Output {
-        Position {
-            x: self.x / rhs,
-            y: self.y / rhs,
-        }
-    }
-}
-
-impl Mul<i64> for Position {
-    type Output = Self;
-    fn mul(self, rhs: i64) -> Self::Output {
-        Position {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        }
-    }
-}
-
-impl Display for Position {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
+/// Which way `y` increases when `North` is up. The methods that don't take a
+/// `YAxis` assume `Up`, like a graph; positions parsed from the rows of a
+/// grid need `Down`, like the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum YAxis {
+    #[default]
+    Up,
+    Down,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -215,6 +474,21 @@ impl Direction {
     }
 
     pub fn offset(self) -> Position {
+        self.offset_with(YAxis::Up)
+    }
+
+    pub fn offset_with(self, y_axis: YAxis) -> Position {
+        let offset = self.up_offset();
+        match y_axis {
+            YAxis::Up => offset,
+            YAxis::Down => Position {
+                x: offset.x,
+                y: -offset.y,
+            },
+        }
+    }
+
+    fn up_offset(self) -> Position {
         use Direction::*;
         match self {
             North => Position { x: 0, y: 1 },
@@ -315,7 +589,254 @@ impl TryFrom<char> for Direction {
             '>' => Ok(Direction::East),
             'v' => Ok(Direction::South),
             '<' => Ok(Direction::West),
-            c => Err(err_msg(format!("Invalid move {}", c))),
+            c => Err(Error::invalid(format!("Invalid move {}", c))),
+        }
+    }
+}
+
+/// A hex in axial coordinates, where the third cube coordinate is
+/// `s = -q - r`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub fn s(self) -> i64 {
+        -self.q - self.r
+    }
+
+    pub fn distance_to(self, other: Hex) -> u64 {
+        (self.q.abs_diff(other.q) + self.r.abs_diff(other.r) + self.s().abs_diff(other.s())) / 2
+    }
+
+    pub fn step(self, direction: impl Into<Hex>) -> Hex {
+        self + direction.into()
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Hex> {
+        PointyHexDirection::all().map(move |dir| self.step(dir))
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Hex {
+            q: self.q + rhs.q,
+            r: self.r + rhs.r,
+        }
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Hex {
+            q: self.q - rhs.q,
+            r: self.r - rhs.r,
+        }
+    }
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s())
+    }
+}
+
+/// Defines a direction between neighbouring hexes, with the variants listed
+/// anticlockwise along with their notation and axial offset.
+macro_rules! hex_direction {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident = $notation:literal ($q:literal, $r:literal)),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl $name {
+            const ALL: [Self; 6] = [$($name::$variant),+];
+
+            pub fn all() -> impl Iterator<Item = Self> + Clone {
+                Self::ALL.into_iter()
+            }
+
+            pub fn offset(self) -> Hex {
+                match self {
+                    $($name::$variant => Hex { q: $q, r: $r }),+
+                }
+            }
+
+            pub fn notation(self) -> &'static str {
+                match self {
+                    $($name::$variant => $notation),+
+                }
+            }
+
+            /// Turn 60° anticlockwise `turns` times, or clockwise if it's
+            /// negative.
+            pub fn rotate(self, turns: i32) -> Self {
+                Self::ALL[(self as i32 + turns).rem_euclid(6) as usize]
+            }
+
+            pub fn turn_left(self) -> Self {
+                self.rotate(1)
+            }
+
+            pub fn turn_right(self) -> Self {
+                self.rotate(-1)
+            }
+
+            pub fn reverse(self) -> Self {
+                self.rotate(3)
+            }
+
+            /// Parse a path of directions, either separated by commas or
+            /// whitespace like `ne,ne,s`, or run together like `esenee`.
+            pub fn parse_path(path: &str) -> Result<Vec<Self>, Error> {
+                let mut directions = vec![];
+                let mut rest = path.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+                while !rest.is_empty() {
+                    let (direction, len) = [2, 1]
+                        .into_iter()
+                        .filter_map(|len| Some((rest.get(..len)?.parse().ok()?, len)))
+                        .next()
+                        .ok_or_else(|| Error::parse_at(path, rest, "Invalid hex direction"))?;
+                    directions.push(direction);
+                    rest = rest[len..].trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+                }
+                Ok(directions)
+            }
+        }
+
+        impl From<$name> for Hex {
+            fn from(direction: $name) -> Hex {
+                direction.offset()
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::all()
+                    .find(|dir| dir.notation().eq_ignore_ascii_case(s))
+                    .ok_or_else(|| Error::invalid(format!("Invalid hex direction {}", s)))
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.notation())
+            }
+        }
+    };
+}
+
+hex_direction! {
+    /// Directions between hexes with pointed tops, which share edges to the
+    /// east and west.
+    PointyHexDirection {
+        East = "e" (1, 0),
+        NorthEast = "ne" (1, -1),
+        NorthWest = "nw" (0, -1),
+        West = "w" (-1, 0),
+        SouthWest = "sw" (-1, 1),
+        SouthEast = "se" (0, 1),
+    }
+}
+
+hex_direction! {
+    /// Directions between hexes with flat tops, which share edges to the
+    /// north and south.
+    FlatHexDirection {
+        North = "n" (0, -1),
+        NorthWest = "nw" (-1, 0),
+        SouthWest = "sw" (-1, 1),
+        South = "s" (0, 1),
+        SouthEast = "se" (1, 0),
+        NorthEast = "ne" (1, -1),
+    }
+}
+
+/// One of the six faces of a cube on a 3D grid, with `Up` along `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction3 {
+    North,
+    South,
+    East,
+    West,
+    Up,
+    Down,
+}
+
+impl Direction3 {
+    pub fn all() -> impl Iterator<Item = Self> + Clone {
+        use Direction3::*;
+        [North, South, East, West, Up, Down].into_iter()
+    }
+
+    /// The offsets to all 26 cubes touching a cube, by a face, edge or
+    /// corner.
+    pub fn all_offsets() -> impl Iterator<Item = Point3<i64>> {
+        Point3::default().all_neighbours()
+    }
+
+    pub fn offset(self) -> Point3<i64> {
+        use Direction3::*;
+        let (x, y, z) = match self {
+            North => (0, 1, 0),
+            South => (0, -1, 0),
+            East => (1, 0, 0),
+            West => (-1, 0, 0),
+            Up => (0, 0, 1),
+            Down => (0, 0, -1),
+        };
+        Point3 { x, y, z }
+    }
+
+    pub fn from_offset(offset: Point3<i64>) -> Option<Self> {
+        Self::all().find(|dir| dir.offset() == offset)
+    }
+
+    pub fn reverse(self) -> Self {
+        use Direction3::*;
+        match self {
+            North => South,
+            South => North,
+            East => West,
+            West => East,
+            Up => Down,
+            Down => Up,
+        }
+    }
+
+    /// Turn a quarter turn anticlockwise looking down `axis` towards the
+    /// origin, following the right-hand rule.
+    pub fn rotate_about(self, axis: Direction3) -> Self {
+        let (a, v) = (axis.offset(), self.offset());
+        let cross = Point3 {
+            x: a.y * v.z - a.z * v.y,
+            y: a.z * v.x - a.x * v.z,
+            z: a.x * v.y - a.y * v.x,
+        };
+        let dot = a.x * v.x + a.y * v.y + a.z * v.z;
+        Self::from_offset(cross + a * dot).unwrap()
+    }
+}
+
+impl TryFrom<char> for Direction3 {
+    type Error = Error;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase() {
+            'N' => Ok(Direction3::North),
+            'S' => Ok(Direction3::South),
+            'E' => Ok(Direction3::East),
+            'W' => Ok(Direction3::West),
+            'U' => Ok(Direction3::Up),
+            'D' => Ok(Direction3::Down),
+            _ => Err(Error::invalid(format!("Invalid move {}", value))),
         }
     }
 }
@@ -356,6 +877,565 @@ pub fn find_all_symbols_in_grid(data: &str, symbol: char) -> impl Iterator<Item
     })
 }
 
+/// One of the 8 rotations and reflections of the square, about the origin.
+/// Rotations are anticlockwise with `YAxis::Up`, so they look clockwise on
+/// grids parsed from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transform {
+    // The matrix mapping (x, y) to (xx * x + xy * y, yx * x + yy * y).
+    xx: i64,
+    xy: i64,
+    yx: i64,
+    yy: i64,
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        xx: 1,
+        xy: 0,
+        yx: 0,
+        yy: 1,
+    };
+
+    /// Rotate by `quarter_turns` of 90°, clockwise if it's negative.
+    pub fn rotate(quarter_turns: i32) -> Self {
+        let left = Transform {
+            xx: 0,
+            xy: -1,
+            yx: 1,
+            yy: 0,
+        };
+        (0..quarter_turns.rem_euclid(4)).fold(Transform::IDENTITY, |t, _| t.then(left))
+    }
+
+    /// Reflect over the `y` axis, negating `x`.
+    pub fn flip_x() -> Self {
+        Transform {
+            xx: -1,
+            xy: 0,
+            yx: 0,
+            yy: 1,
+        }
+    }
+
+    /// Reflect over the `x` axis, negating `y`.
+    pub fn flip_y() -> Self {
+        Transform {
+            xx: 1,
+            xy: 0,
+            yx: 0,
+            yy: -1,
+        }
+    }
+
+    /// Reflect over the diagonal `y = x`, swapping `x` and `y`.
+    pub fn transpose() -> Self {
+        Transform {
+            xx: 0,
+            xy: 1,
+            yx: 1,
+            yy: 0,
+        }
+    }
+
+    /// Reflect over the diagonal `y = -x`.
+    pub fn anti_transpose() -> Self {
+        Transform {
+            xx: 0,
+            xy: -1,
+            yx: -1,
+            yy: 0,
+        }
+    }
+
+    /// All 8 symmetries of the square, starting with the identity.
+    pub fn all() -> impl Iterator<Item = Self> + Clone {
+        (0..4)
+            .map(Transform::rotate)
+            .chain((0..4).map(|turns| Transform::flip_x().then(Transform::rotate(turns))))
+    }
+
+    /// The transform that applies `self` and then `next`.
+    pub fn then(self, next: Transform) -> Self {
+        Transform {
+            xx: next.xx * self.xx + next.xy * self.yx,
+            xy: next.xx * self.xy + next.xy * self.yy,
+            yx: next.yx * self.xx + next.yy * self.yx,
+            yy: next.yx * self.xy + next.yy * self.yy,
+        }
+    }
+
+    pub fn inverse(self) -> Self {
+        // Every symmetry of the square is orthogonal, so its inverse is its
+        // transpose.
+        Transform {
+            xx: self.xx,
+            xy: self.yx,
+            yx: self.xy,
+            yy: self.yy,
+        }
+    }
+
+    /// Whether this swaps the `x` and `y` axes, and so the width and height
+    /// of a grid.
+    pub fn swaps_axes(self) -> bool {
+        self.xx == 0
+    }
+
+    pub fn apply(self, pos: Position) -> Position {
+        Position {
+            x: self.xx * pos.x + self.xy * pos.y,
+            y: self.yx * pos.x + self.yy * pos.y,
+        }
+    }
+
+    pub fn apply_about(self, pos: Position, centre: Position) -> Position {
+        centre + self.apply(pos - centre)
+    }
+
+    pub fn apply_all<I>(self, positions: I) -> impl Iterator<Item = Position>
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        positions.into_iter().map(move |pos| self.apply(pos))
+    }
+
+    pub fn apply_set(self, positions: &HashSet<Position>) -> HashSet<Position> {
+        self.apply_all(positions.iter().copied()).collect()
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::IDENTITY
+    }
+}
+
+/// A dense, rectangular grid with `(0, 0)` at the top left and `y`
+/// increasing down the rows, so directions need `YAxis::Down`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics unless there are `width * height` cells and neither is zero.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0 && height > 0, "Grids can't be empty");
+        assert_eq!(cells.len(), width * height);
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parse a grid of characters, mapping each to a cell with `cell`. Every
+    /// row must be the same width.
+    pub fn parse<F>(data: &str, mut cell: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let width = data
+            .lines()
+            .next()
+            .map(|row| row.chars().count())
+            .filter(|&width| width > 0)
+            .ok_or_else(|| Error::invalid("Empty input"))?;
+
+        let mut cells = vec![];
+        let mut height = 0;
+        for row in data.lines() {
+            if row.chars().count() != width {
+                return Err(Error::parse_at(
+                    data,
+                    row,
+                    format!("Row isn't {} characters wide like the first", width),
+                ));
+            }
+
+            for (index, c) in row.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    Error::parse_at(data, &row[index..], format!("Invalid cell {:?}", c))
+                })?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        self.cell_index(pos).is_some()
+    }
+
+    fn cell_index(&self, pos: Position) -> Option<usize> {
+        let x = usize::try_from(pos.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(pos.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn cell_position(&self, index: usize) -> Position {
+        (index % self.width, index / self.width).into()
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.cell_index(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.cell_index(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// The cells up, down, left and right of `pos` that are on the grid.
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        pos.adjacent()
+            .filter_map(|adj| self.get(adj).map(|cell| (adj, cell)))
+    }
+
+    /// The cells around `pos`, including diagonals, that are on the grid.
+    pub fn surrounding(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        pos.surrounding()
+            .filter_map(|adj| self.get(adj).map(|cell| (adj, cell)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.cell_position(index), cell))
+    }
+
+    pub fn find<P>(&self, predicate: P) -> Option<Position>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.cell_position(index))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Rotate or reflect the grid, moving it back so its top left is at
+    /// `(0, 0)`.
+    pub fn transform(&self, transform: Transform) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let (width, height) = if transform.swaps_axes() {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        };
+
+        // The corner that ends up at the top left.
+        let corner = [
+            (0, 0),
+            (self.width - 1, 0),
+            (0, self.height - 1),
+            (self.width - 1, self.height - 1),
+        ]
+        .into_iter()
+        .map(|corner| transform.apply(corner.into()))
+        .min_by_key(|pos| (pos.y, pos.x))
+        .unwrap();
+
+        let inverse = transform.inverse();
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Position::from((x, y))))
+            .map(|pos| self[inverse.apply(pos + corner)].clone())
+            .collect();
+        Grid::new(width, height, cells)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A grid of cells scattered over an unbounded plane, keeping track of the
+/// bounding box of the occupied cells.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Position, T>,
+    bounds: Cell<Option<(Position, Position)>>,
+    // Removing a cell can shrink the bounds, so they're recalculated the
+    // next time they're needed.
+    stale: Cell<bool>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: Cell::new(None),
+            stale: Cell::new(false),
+        }
+    }
+
+    /// Parse a grid of characters, keeping the cells that `cell` maps to a
+    /// value. Like `Grid`, `y` increases down the rows.
+    pub fn parse<F>(data: &str, mut cell: F) -> Self
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        data.lines()
+            .enumerate()
+            .flat_map(|(y, row)| row.chars().enumerate().map(move |(x, c)| ((x, y), c)))
+            .filter_map(|(pos, c)| cell(c).map(|value| (pos.into(), value)))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn insert(&mut self, pos: Position, value: T) -> Option<T> {
+        if !self.stale.get() {
+            let bounds = match self.bounds.get() {
+                Some((min, max)) => (
+                    Position {
+                        x: min.x.min(pos.x),
+                        y: min.y.min(pos.y),
+                    },
+                    Position {
+                        x: max.x.max(pos.x),
+                        y: max.y.max(pos.y),
+                    },
+                ),
+                None => (pos, pos),
+            };
+            self.bounds.set(Some(bounds));
+        }
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Position) -> Option<T> {
+        let removed = self.cells.remove(&pos);
+        if removed.is_some() {
+            self.stale.set(true);
+        }
+        removed
+    }
+
+    pub fn retain<F: FnMut(Position, &mut T) -> bool>(&mut self, mut keep: F) {
+        let len = self.cells.len();
+        self.cells.retain(|&pos, value| keep(pos, value));
+        if self.cells.len() != len {
+            self.stale.set(true);
+        }
+    }
+
+    /// The smallest and largest corners of the box around every occupied
+    /// cell, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        if self.stale.replace(false) {
+            let xs = self.cells.keys().map(|pos| pos.x).minmax().into_option();
+            let ys = self.cells.keys().map(|pos| pos.y).minmax().into_option();
+            self.bounds
+                .set(xs.zip(ys).map(|((min_x, max_x), (min_y, max_y))| {
+                    (
+                        Position { x: min_x, y: min_y },
+                        Position { x: max_x, y: max_y },
+                    )
+                }));
+        }
+        self.bounds.get()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// The occupied cells up, down, left and right of `pos`.
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        pos.adjacent()
+            .filter_map(|adj| self.get(adj).map(|value| (adj, value)))
+    }
+
+    /// The occupied cells around `pos`, including diagonals.
+    pub fn surrounding(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        pos.surrounding()
+            .filter_map(|adj| self.get(adj).map(|value| (adj, value)))
+    }
+
+    /// The occupied cells reachable from `start` by stepping in any of
+    /// `directions` through other occupied cells. Grids from `parse` need
+    /// `YAxis::Down`.
+    pub fn flood_fill<D>(&self, start: Position, directions: D, y_axis: YAxis) -> HashSet<Position>
+    where
+        D: Iterator<Item = Direction> + Clone,
+    {
+        let mut filled = HashSet::new();
+        let mut stack: Vec<_> = Some(start)
+            .filter(|&pos| self.contains(pos))
+            .into_iter()
+            .collect();
+
+        while let Some(pos) = stack.pop() {
+            if filled.insert(pos) {
+                stack.extend(
+                    directions
+                        .clone()
+                        .map(|dir| pos.step_with(dir, y_axis))
+                        .filter(|next| self.contains(*next) && !filled.contains(next)),
+                );
+            }
+        }
+
+        filled
+    }
+
+    /// One generation of a cellular automaton: `rule` is given each occupied
+    /// cell and each empty cell next to one, and returns the cell's new value.
+    pub fn evolve<F>(&self, mut rule: F) -> Self
+    where
+        F: FnMut(Position, Option<&T>) -> Option<T>,
+    {
+        let candidates: HashSet<_> = self
+            .positions()
+            .flat_map(|pos| pos.surrounding().chain([pos]))
+            .collect();
+
+        candidates
+            .into_iter()
+            .filter_map(|pos| rule(pos, self.get(pos)).map(|value| (pos, value)))
+            .collect()
+    }
+
+    /// Rotate or reflect every cell about the origin.
+    pub fn transform(&self, transform: Transform) -> Self
+    where
+        T: Clone,
+    {
+        self.iter()
+            .map(|(pos, value)| (transform.apply(pos), value.clone()))
+            .collect()
+    }
+
+    /// Draw the cells between the corners `min` and `max`, inclusive, one
+    /// row per line.
+    pub fn render<F>(&self, (min, max): (Position, Position), mut cell: F) -> String
+    where
+        F: FnMut(Option<&T>) -> char,
+    {
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| cell(self.get(Position { x, y })))
+                    .chain(['\n'])
+                    .collect::<String>()
+            })
+            .collect()
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Position, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Position, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
 pub struct Counter<T> {
     counts: HashMap<T, usize>,
 }
@@ -392,6 +1472,8 @@ impl<T: Hash + Eq> IntoIterator for Counter<T> {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+
     #[test]
     fn test_angle() {
         use super::Direction::*;
@@ -399,4 +1481,307 @@ mod test {
         assert_eq!(NorthWest.angle_to(NorthEast), 90);
         assert_eq!(South.angle_to(East), 270);
     }
+
+    #[test]
+    fn test_grid() {
+        let pos = |x, y| Position { x, y };
+        let grid = Grid::parse("#..\n.#.\n", |c| match c {
+            '#' => Some(1),
+            '.' => Some(0),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(pos(1, 1)), Some(&1));
+        assert_eq!(grid.get(pos(3, 0)), None);
+        assert_eq!(grid.get(pos(-1, 0)), None);
+        assert_eq!(grid.find(|&cell| cell == 1), Some(pos(0, 0)));
+
+        let neighbours: Vec<_> = grid.neighbours(pos(0, 0)).collect();
+        assert_eq!(neighbours, [(pos(1, 0), &0), (pos(0, 1), &0)]);
+        assert_eq!(grid.surrounding(pos(0, 0)).count(), 3);
+        assert_eq!(grid.surrounding(pos(1, 1)).count(), 5);
+
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 0, 0], [0, 1, 0]]);
+        let columns: Vec<Vec<_>> = grid.columns().map(|col| col.copied().collect()).collect();
+        assert_eq!(columns, [[1, 0], [0, 1], [0, 0]]);
+        assert_eq!(grid.to_string(), "100\n010\n");
+
+        let invalid = Grid::parse("..\n.x\n", |c| (c == '.').then_some(()));
+        assert_eq!(
+            invalid.unwrap_err().to_string(),
+            "line 2, column 2: Invalid cell 'x'\n  .x\n   ^"
+        );
+        assert!(Grid::parse("..\n.\n", Some).is_err());
+    }
+
+    #[test]
+    fn test_y_axis() {
+        use super::Direction::*;
+        let origin = Position::origin();
+
+        assert_eq!(origin.step(North), Position { x: 0, y: 1 });
+        assert_eq!(origin.step_by(NorthEast, 2), Position { x: 2, y: 2 });
+        assert_eq!(
+            origin.direction_to(Position { x: -1, y: 3 }),
+            Some(NorthWest)
+        );
+        assert_eq!(origin.direction_to(origin), None);
+        assert_eq!(
+            origin.direction_to(Position { x: 1, y: 100 }),
+            Some(NorthEast)
+        );
+
+        assert_eq!(
+            origin.step_with(North, YAxis::Down),
+            Position { x: 0, y: -1 }
+        );
+        assert_eq!(
+            origin.step_by_with(NorthEast, 2, YAxis::Down),
+            Position { x: 2, y: -2 }
+        );
+        assert_eq!(
+            origin.direction_to_with(Position { x: -1, y: 3 }, YAxis::Down),
+            Some(SouthWest)
+        );
+
+        for y_axis in [YAxis::Up, YAxis::Down] {
+            for dir in Direction::all() {
+                let pos = origin.step_with(dir, y_axis);
+                assert_eq!(origin.direction_to_with(pos, y_axis), Some(dir));
+                assert_eq!(pos.step_with(dir.reverse(), y_axis), origin);
+            }
+        }
+
+        // On a parsed grid, `^` and North both point up the screen.
+        let grid = Grid::parse("#.\n^.\n", Some).unwrap();
+        let arrow = grid.find(|&c| c == '^').unwrap();
+        let dir = Direction::try_from(grid[arrow]).unwrap();
+        assert_eq!(grid[arrow.step_with(dir, YAxis::Down)], '#');
+        assert_eq!(grid[arrow.step_with(dir.turn_right(), YAxis::Down)], '.');
+        assert_eq!(
+            arrow.step_with(dir.turn_right(), YAxis::Down),
+            Position { x: 1, y: 1 }
+        );
+    }
+
+    #[test]
+    fn test_transforms() {
+        let pos = |x, y| Position { x, y };
+        let left = Transform::rotate(1);
+        assert_eq!(left.apply(pos(2, 1)), pos(-1, 2));
+        assert_eq!(Transform::rotate(-1).apply(pos(2, 1)), pos(1, -2));
+        assert_eq!(left.then(left), Transform::rotate(2));
+        assert_eq!(Transform::rotate(4), Transform::IDENTITY);
+        assert_eq!(Transform::flip_x().apply(pos(2, 1)), pos(-2, 1));
+        assert_eq!(Transform::flip_y().apply(pos(2, 1)), pos(2, -1));
+        assert_eq!(Transform::transpose().apply(pos(2, 1)), pos(1, 2));
+        assert_eq!(Transform::anti_transpose().apply(pos(2, 1)), pos(-1, -2));
+        assert_eq!(Transform::flip_x().then(left), Transform::anti_transpose());
+        assert_eq!(left.apply_about(pos(3, 1), pos(1, 1)), pos(1, 3));
+
+        let all: HashSet<_> = Transform::all().collect();
+        assert_eq!(all.len(), 8);
+        for &a in all.iter() {
+            assert_eq!(a.then(a.inverse()), Transform::IDENTITY);
+            assert!(all.iter().all(|&b| all.contains(&a.then(b))));
+        }
+
+        let shape: HashSet<_> = [pos(0, 0), pos(1, 0), pos(1, 1)].into_iter().collect();
+        let rotated: HashSet<_> = [pos(0, 0), pos(0, 1), pos(-1, 1)].into_iter().collect();
+        assert_eq!(left.apply_set(&shape), rotated);
+
+        let grid = Grid::parse("ab\ncd\nef\n", Some).unwrap();
+        assert_eq!(grid.transform(Transform::IDENTITY), grid);
+        assert_eq!(grid.transform(left).to_string(), "eca\nfdb\n");
+        assert_eq!(
+            grid.transform(Transform::flip_x()).to_string(),
+            "ba\ndc\nfe\n"
+        );
+        assert_eq!(
+            grid.transform(Transform::transpose()).to_string(),
+            "ace\nbdf\n"
+        );
+        let variants: HashSet<_> = Transform::all().map(|t| grid.transform(t)).collect();
+        assert_eq!(variants.len(), 8);
+
+        let sparse = SparseGrid::parse("#.\n##\n", |c| (c == '#').then_some(()));
+        let turned = sparse.transform(Transform::rotate(2));
+        assert_eq!(turned.bounds(), Some((pos(-1, -1), pos(0, 0))));
+    }
+
+    #[test]
+    fn test_lines() {
+        let pos = |x, y| Position { x, y };
+
+        assert_eq!(
+            pos(1, 1).points_to(pos(1, 4)).map(Vec::from_iter),
+            Some(vec![pos(1, 1), pos(1, 2), pos(1, 3)])
+        );
+        assert_eq!(
+            pos(2, 2).points_to_inclusive(pos(0, 0)).map(Vec::from_iter),
+            Some(vec![pos(2, 2), pos(1, 1), pos(0, 0)])
+        );
+        assert_eq!(pos(0, 0).points_to(pos(0, 0)).unwrap().count(), 0);
+        assert_eq!(pos(0, 0).points_to_inclusive(pos(0, 0)).unwrap().count(), 1);
+        assert!(pos(0, 0).points_to(pos(1, 2)).is_none());
+
+        let points: Vec<_> = pos(0, 0).bresenham_to(pos(4, -2)).collect();
+        assert_eq!(
+            points,
+            [pos(0, 0), pos(1, -1), pos(2, -1), pos(3, -2), pos(4, -2)]
+        );
+        assert_eq!(pos(0, 0).bresenham_to_exclusive(pos(4, -2)).count(), 4);
+        assert_eq!(
+            pos(3, 3).bresenham_to(pos(3, 3)).collect::<Vec<_>>(),
+            [pos(3, 3)]
+        );
+
+        for end in [pos(5, 0), pos(-3, -3), pos(0, 7), pos(-4, 4)] {
+            let straight: Vec<_> = pos(0, 0).points_to_inclusive(end).unwrap().collect();
+            let bresenham: Vec<_> = pos(0, 0).bresenham_to(end).collect();
+            assert_eq!(straight, bresenham);
+        }
+        for end in [pos(7, 3), pos(-2, 9), pos(-8, -5), pos(1, -6)] {
+            let points: Vec<_> = pos(0, 0).bresenham_to(end).collect();
+            assert_eq!(points.last(), Some(&end));
+            assert!(
+                points
+                    .windows(2)
+                    .all(|step| step[0].surrounding().any(|p| p == step[1]))
+            );
+        }
+    }
+
+    #[test]
+    fn test_hex_directions() {
+        use super::PointyHexDirection::*;
+        let path = PointyHexDirection::parse_path("esenee").unwrap();
+        assert_eq!(path, [East, SouthEast, NorthEast, East]);
+        let end = path.into_iter().fold(Hex::default(), Hex::step);
+        assert_eq!(end, Hex { q: 3, r: 0 });
+        assert_eq!(Hex::default().distance_to(end), 3);
+
+        let path = FlatHexDirection::parse_path("ne,ne,s,s").unwrap();
+        let end = path.into_iter().fold(Hex::default(), Hex::step);
+        assert_eq!(Hex::default().distance_to(end), 2);
+        assert!(FlatHexDirection::parse_path("n,e").is_err());
+        assert!(FlatHexDirection::parse_path("n, s\n").is_ok());
+
+        for dir in PointyHexDirection::all() {
+            assert_eq!(Hex::default().step(dir).step(dir.reverse()), Hex::default());
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.rotate(6), dir);
+            assert_eq!(dir.notation().parse::<PointyHexDirection>().unwrap(), dir);
+        }
+        assert_eq!(East.turn_left(), NorthEast);
+        assert_eq!(East.rotate(-2), SouthWest);
+        assert_eq!(
+            FlatHexDirection::North.turn_right(),
+            FlatHexDirection::NorthEast
+        );
+        assert_eq!(Hex::default().neighbours().count(), 6);
+    }
+
+    #[test]
+    fn test_direction3() {
+        use super::Direction3::*;
+        assert_eq!(Direction3::all().count(), 6);
+        assert_eq!(Direction3::all_offsets().count(), 26);
+        assert_eq!(Direction3::try_from('u').unwrap(), Up);
+        assert!(Direction3::try_from('x').is_err());
+
+        assert_eq!(East.rotate_about(Up), North);
+        assert_eq!(North.rotate_about(East), Up);
+        assert_eq!(Up.rotate_about(North), East);
+        assert_eq!(Up.rotate_about(Up), Up);
+        for dir in Direction3::all() {
+            assert_eq!(dir.reverse().reverse(), dir);
+            assert_eq!(Direction3::from_offset(dir.offset()), Some(dir));
+            let axis = Direction3::all().find(|&axis| axis != dir && axis != dir.reverse());
+            let axis = axis.unwrap();
+            let turned = (0..4).fold(dir, |dir, _| dir.rotate_about(axis));
+            assert_eq!(turned, dir);
+        }
+    }
+
+    #[test]
+    fn test_points() {
+        let a = Point3 { x: 1, y: -2, z: 3 };
+        let b = Point3 { x: 4, y: 2, z: 3 };
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(a.squared_distance(b), 25);
+        assert_eq!(b - a, Point3 { x: 3, y: 4, z: 0 });
+        assert_eq!(-a * 2, Point3 { x: -2, y: 4, z: -6 });
+        assert_eq!(a.to_string(), "(1, -2, 3)");
+        assert_eq!(a.neighbours().count(), 6);
+        assert_eq!(a.all_neighbours().count(), 26);
+
+        let origin = Point2 { x: 0_u32, y: 0 };
+        let neighbours: Vec<_> = origin.neighbours().collect();
+        assert_eq!(neighbours, [Point2 { x: 1, y: 0 }, Point2 { x: 0, y: 1 }]);
+        assert_eq!(origin.all_neighbours().count(), 3);
+
+        let point = PointN([0_i8; 4]);
+        assert_eq!(point.all_neighbours().count(), 80);
+        assert_eq!(
+            PointN([1, 2, 3, 4]).manhattan_distance(point.0.map(i32::from).into()),
+            10
+        );
+        assert_eq!(PointN([1, 2, 3, 4]).to_string(), "(1, 2, 3, 4)");
+    }
+
+    #[test]
+    #[should_panic(expected = "Grids can't be empty")]
+    fn test_empty_grid() {
+        Grid::filled(0, 3, ());
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let pos = |x, y| Position { x, y };
+        let mut grid = SparseGrid::parse(".#.\n.##\n...\n", |c| (c == '#').then_some(()));
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.bounds(), Some((pos(1, 0), pos(2, 1))));
+
+        grid.insert(pos(-2, 4), ());
+        assert_eq!(grid.bounds(), Some((pos(-2, 0), pos(2, 4))));
+        grid.remove(pos(-2, 4));
+        assert_eq!(grid.bounds(), Some((pos(1, 0), pos(2, 1))));
+        assert_eq!(grid.surrounding(pos(2, 0)).count(), 3);
+        assert_eq!(grid.neighbours(pos(2, 0)).count(), 2);
+
+        let filled = grid.flood_fill(pos(1, 0), Direction::cardinal(), YAxis::Down);
+        assert_eq!(filled.len(), 3);
+        assert!(
+            grid.flood_fill(pos(0, 0), Direction::all(), YAxis::Down)
+                .is_empty()
+        );
+        let north = |y_axis| grid.flood_fill(pos(1, 1), [Direction::North].into_iter(), y_axis);
+        assert_eq!(
+            north(YAxis::Down),
+            [pos(1, 1), pos(1, 0)].into_iter().collect()
+        );
+        assert_eq!(north(YAxis::Up), [pos(1, 1)].into_iter().collect());
+
+        let render = |grid: &SparseGrid<()>| {
+            grid.render((pos(0, 0), pos(2, 2)), |cell| {
+                if cell.is_some() { '#' } else { '.' }
+            })
+        };
+        assert_eq!(render(&grid), ".#.\n.##\n...\n");
+
+        // Conway's life turns the L into a block.
+        let next = grid.evolve(|pos, cell| {
+            let neighbours = grid.surrounding(pos).count();
+            (neighbours == 3 || (neighbours == 2 && cell.is_some())).then_some(())
+        });
+        assert_eq!(render(&next), ".##\n.##\n...\n");
+
+        grid.retain(|_, _| false);
+        assert_eq!(grid.bounds(), None);
+        grid.insert(pos(5, 5), ());
+        assert_eq!(grid.bounds(), Some((pos(5, 5), pos(5, 5))));
+    }
 }
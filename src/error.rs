@@ -0,0 +1,184 @@
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Everything that can go wrong while fetching, parsing, solving or
+/// submitting a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    /// Reading or writing a local file, such as an input or the cache.
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    /// Talking to the Advent of Code server.
+    Network(String),
+    /// Malformed input, with the 1-based position of the problem and the
+    /// line it's on, and the file it's in if it's not the puzzle input.
+    Parse {
+        path: Option<PathBuf>,
+        line: usize,
+        column: usize,
+        snippet: String,
+        message: String,
+    },
+    /// A solver that failed or panicked.
+    Solver(String),
+    /// An answer that mustn't be submitted.
+    Refused(String),
+    /// A request that can't be carried out, such as an unknown day or
+    /// conflicting options.
+    Invalid(String),
+}
+
+impl Error {
+    pub fn io<P: AsRef<Path>>(path: P, source: io::Error) -> Self {
+        Error::Io {
+            path: Some(path.as_ref().to_path_buf()),
+            source,
+        }
+    }
+
+    pub fn invalid<S: Into<String>>(message: S) -> Self {
+        Error::Invalid(message.into())
+    }
+
+    /// A parse error at `at`, which must be a slice of `input`, such as the
+    /// remaining input of a failed parser or one of `input.lines()`.
+    pub fn parse_at<S: Into<String>>(input: &str, at: &str, message: S) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+
+        Error::Parse {
+            path: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Attach the file that a parse error is in.
+    pub fn in_file<P: AsRef<Path>>(self, file: P) -> Self {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                snippet,
+                message,
+                ..
+            } => Error::Parse {
+                path: Some(file.as_ref().to_path_buf()),
+                line,
+                column,
+                snippet,
+                message,
+            },
+            err => err,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "{}: {}", path.display(), source),
+            Error::Io { path: None, source } => write!(f, "{}", source),
+            Error::Network(message) => write!(f, "Server error: {}", message),
+            Error::Parse {
+                path,
+                line,
+                column,
+                snippet,
+                message,
+            } => {
+                if let Some(path) = path {
+                    write!(f, "{}: ", path.display())?;
+                }
+                write!(f, "line {}, column {}: {}", line, column, message)?;
+                if !snippet.is_empty() {
+                    write!(f, "\n  {}\n  {:>column$}", snippet, "^", column = column)?;
+                }
+                Ok(())
+            }
+            Error::Solver(message) => write!(f, "Solver failed: {}", message),
+            Error::Refused(message) | Error::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(source: io::Error) -> Self {
+        Error::Io { path: None, source }
+    }
+}
+
+/// Only used when writing JSON; reading it reports errors with the file.
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Invalid(format!("Couldn't write JSON: {}", err))
+    }
+}
+
+/// `aocf` reports everything through `failure`, and only ever while talking
+/// to the server.
+impl From<failure::Error> for Error {
+    fn from(err: failure::Error) -> Self {
+        Error::Network(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_at() {
+        let input = "abc\ndef\r\nghi\n";
+        let Error::Parse {
+            line,
+            column,
+            snippet,
+            ..
+        } = Error::parse_at(input, &input[6..], "bad")
+        else {
+            panic!("not a parse error");
+        };
+        assert_eq!((line, column, snippet.as_str()), (2, 3, "def"));
+
+        let line = input.lines().nth(2).unwrap();
+        assert_eq!(
+            Error::parse_at(input, line, "bad").to_string(),
+            "line 3, column 1: bad\n  ghi\n  ^"
+        );
+
+        let end = Error::parse_at(input, &input[input.len()..], "eof");
+        assert_eq!(end.to_string(), "line 4, column 1: eof");
+        assert_eq!(
+            end.in_file("answers.txt").to_string(),
+            "answers.txt: line 4, column 1: eof"
+        );
+    }
+}
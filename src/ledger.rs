@@ -0,0 +1,278 @@
+use crate::Error;
+use crate::Part;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The server's judgement of a submitted answer.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Submitted too soon after a previous answer, so not judged at all.
+    Wait,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("answer is too high") {
+            Verdict::TooHigh
+        } else if response.contains("answer is too low") {
+            Verdict::TooLow
+        } else if response.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if response.contains("answer too recently") {
+            Verdict::Wait
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::Wait => write!(f, "wait"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// One submitted answer, as the text that was sent to the server.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl Submission {
+    fn age(&self, now: SystemTime) -> String {
+        let elapsed = now
+            .duration_since(UNIX_EPOCH + Duration::from_secs(self.timestamp))
+            .unwrap_or_default()
+            .as_secs();
+        match elapsed {
+            0..60 => format!("{}s ago", elapsed),
+            60..3600 => format!("{}m ago", elapsed / 60),
+            3600..86400 => format!("{}h ago", elapsed / 3600),
+            _ => format!("{}d ago", elapsed / 86400),
+        }
+    }
+}
+
+/// Every answer submitted for a year, kept as JSON so that wrong answers are
+/// never sent twice.
+pub struct Ledger {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Load the ledger at `path`, starting an empty one if it doesn't exist.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let submissions = match read_to_string(&path) {
+            Ok(data) => serde_json::from_str(&data).map_err(|err| {
+                Error::invalid(format!(
+                    "Invalid submission ledger {}: {}",
+                    path.display(),
+                    err
+                ))
+            })?,
+            Err(err) if err.kind() == ErrorKind::NotFound => vec![],
+            Err(err) => return Err(Error::io(path, err)),
+        };
+
+        Ok(Ledger { path, submissions })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        if let Some(parent) = self.path.parent() {
+            create_dir_all(parent).map_err(|err| Error::io(parent, err))?;
+        }
+        write(&self.path, serde_json::to_string_pretty(&self.submissions)?)
+            .map_err(|err| Error::io(&self.path, err))
+    }
+
+    /// The submissions for a day, oldest first.
+    pub fn history(&self, day: u32) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |submission| submission.day == day)
+    }
+
+    /// Check an answer before submitting it, refusing one that is already
+    /// known to be wrong or for a part that's already solved, and returning
+    /// warnings for a numeric answer outside the bounds already recorded.
+    pub fn check(&self, day: u32, part: Part, answer: &str) -> Result<Vec<String>, Error> {
+        let previous = || self.history(day).filter(|s| s.part == part);
+
+        if let Some(correct) = previous().find(|s| s.verdict == Verdict::Correct) {
+            return Err(Error::Refused(format!(
+                "Day {} part {} was already solved with {}",
+                day, part, correct.answer
+            )));
+        }
+
+        if let Some(wrong) = previous().find(|s| s.answer == answer && s.verdict.is_wrong()) {
+            return Err(Error::Refused(format!(
+                "Refusing to resubmit {}, it was already rejected as {}",
+                answer, wrong.verdict
+            )));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(vec![]);
+        };
+
+        let bound = |verdict| {
+            previous()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        let mut warnings = vec![];
+        if let Some(high) = bound(Verdict::TooHigh).filter(|&high| value >= high).min() {
+            warnings.push(format!(
+                "{} is at least {}, which was too high",
+                value, high
+            ));
+        }
+        if let Some(low) = bound(Verdict::TooLow).filter(|&low| value <= low).max() {
+            warnings.push(format!("{} is at most {}, which was too low", value, low));
+        }
+        Ok(warnings)
+    }
+
+    pub fn record(&mut self, day: u32, part: Part, answer: &str, verdict: Verdict) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.submissions.push(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            timestamp,
+        });
+    }
+
+    /// The days with any submissions, in order.
+    pub fn days(&self) -> Vec<u32> {
+        let mut days: Vec<_> = self.submissions.iter().map(|s| s.day).collect();
+        days.sort();
+        days.dedup();
+        days
+    }
+}
+
+pub fn display_history(ledger: &Ledger, days: &[u32]) {
+    let now = SystemTime::now();
+    for &day in days {
+        println!("Day {}", day);
+        let mut any = false;
+        for submission in ledger.history(day) {
+            println!(
+                "  Part {}: {} ({}, {})",
+                submission.part,
+                submission.answer,
+                submission.verdict,
+                submission.age(now)
+            );
+            any = true;
+        }
+        if !any {
+            println!("  No submissions");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer"),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer. If you're stuck"),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently; you have 42s left"),
+            Verdict::Wait
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("2025").join("submissions.json");
+
+        let mut ledger = Ledger::load(&path).unwrap();
+        assert!(ledger.check(1, Part::One, "100").unwrap().is_empty());
+
+        ledger.record(1, Part::One, "100", Verdict::TooHigh);
+        ledger.record(1, Part::One, "10", Verdict::TooLow);
+        ledger.record(1, Part::One, "50", Verdict::Wait);
+        ledger.save().unwrap();
+
+        let ledger = Ledger::load(&path).unwrap();
+        assert_eq!(ledger.history(1).count(), 3);
+        assert!(ledger.check(1, Part::One, "100").is_err());
+        assert!(ledger.check(1, Part::One, "10").is_err());
+        assert!(ledger.check(1, Part::One, "50").unwrap().is_empty());
+        assert_eq!(ledger.check(1, Part::One, "150").unwrap().len(), 1);
+        assert_eq!(ledger.check(1, Part::One, "5").unwrap().len(), 1);
+        assert!(ledger.check(1, Part::Two, "100").unwrap().is_empty());
+        assert!(ledger.check(1, Part::One, "abc").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_already_solved() {
+        let dir = tempdir().unwrap();
+        let mut ledger = Ledger::load(dir.path().join("submissions.json")).unwrap();
+
+        ledger.record(2, Part::Two, "42", Verdict::Correct);
+        assert!(ledger.check(2, Part::Two, "43").is_err());
+        assert!(ledger.check(2, Part::One, "43").is_ok());
+        assert_eq!(ledger.days(), vec![2]);
+    }
+}
@@ -1,26 +1,38 @@
-use aocf::Aoc;
-use failure::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::path::Path;
 use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 mod a_star;
+mod answer;
+mod answers;
+mod backend;
+mod bench;
+mod cache;
 mod common;
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
 mod djikstra;
+mod error;
+mod ledger;
+mod output;
 mod parsers;
+mod scaffold;
 
-#[derive(Debug, Eq, PartialEq)]
+pub use answer::Answer;
+pub use answers::{Expected, Outcome, read_answers, read_examples, verify};
+pub use backend::{AocServer, InputSource, MockServer, Submitter, init_aoc};
+pub use bench::{Benchmark, Stats, bench_day, display_benchmarks};
+pub use cache::InputCache;
+pub use error::Error;
+pub use ledger::{Ledger, Submission, Verdict, display_history};
+pub use output::{Output, Record, display_solutions};
+pub use scaffold::new_day;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Part {
     One,
     Two,
@@ -38,30 +50,159 @@ impl FromStr for Part {
     }
 }
 
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Days(Vec<u32>);
+
+impl Days {
+    /// Every implemented day of the year.
+    pub fn all(year: i32) -> Result<Self, Error> {
+        let solvers = find_event(year)?.solvers;
+        Ok(Days(solvers.iter().map(|solver| solver.day).collect()))
+    }
+
+    /// These days, as long as they're all in the year's event.
+    pub fn in_event(self, year: i32) -> Result<Self, Error> {
+        let last_day = find_event(year)?.last_day;
+        let invalid = self.iter().find(|&day| day > last_day);
+        match invalid {
+            Some(day) => Err(Error::invalid(format!("Invalid day {}", day))),
+            None => Ok(self),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0.iter().cloned()
+    }
+}
+
+/// No event has more days than this, which also keeps ranges small.
+const MAX_DAY: u32 = 25;
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            day.trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|day| (1..=MAX_DAY).contains(day))
+                .ok_or_else(|| format!("Invalid day {}", day))
+        };
+
+        let mut days = vec![];
+        for item in s.split(',') {
+            if let Some((start, end)) = item.split_once('-') {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("Invalid day range {}", item));
+                }
+                days.extend(start..=end);
+            } else {
+                days.push(parse_day(item)?);
+            }
+        }
+
+        days.sort();
+        days.dedup();
+        Ok(Days(days))
+    }
+}
+
+/// A solver for one day's puzzle.
+///
+/// Implement `part_one` and `part_two`, so that each part can be run and
+/// timed on its own. For days where the parts share their work, set
+/// `COMBINED` and implement `solve` instead, which is run once and timed as a
+/// whole.
 pub trait Solver {
+    /// The puzzle's title, as shown on its page.
+    const TITLE: &'static str;
+
+    /// Whether both parts are solved together by `solve`.
+    const COMBINED: bool = false;
+
     type Problem;
 
     fn parse_input(data: String) -> Result<Self::Problem, Error>;
-    fn solve(problem: Self::Problem) -> (Option<String>, Option<String>);
+
+    fn part_one(_problem: &Self::Problem) -> Option<Answer> {
+        None
+    }
+
+    fn part_two(_problem: &Self::Problem) -> Option<Answer> {
+        None
+    }
+
+    fn solve(problem: Self::Problem) -> (Option<Answer>, Option<Answer>) {
+        (Self::part_one(&problem), Self::part_two(&problem))
+    }
 }
 
-fn read_from_server(aoc: &mut Aoc) -> Result<String, Error> {
-    aoc.get_input(false)
+/// Tidy up input that's been through an editor: drop a byte order mark,
+/// convert CRLF line endings and end with exactly one newline.
+pub fn normalise_input(data: &str) -> String {
+    let data = data.strip_prefix('\u{feff}').unwrap_or(data);
+    let data = data.replace("\r\n", "\n");
+    let data = data.trim_end_matches('\n');
+
+    if data.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", data)
+    }
 }
 
-pub fn read_input<P: AsRef<Path>>(path: Option<P>, aoc: &mut Aoc) -> Result<String, Error> {
-    if let Some(path) = &path {
-        Ok(read_to_string(path)?)
+/// Read the file at `path`, or standard input if `path` is `-`.
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<String, Error> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut data = String::new();
+        io::stdin().read_to_string(&mut data)?;
+        Ok(data)
     } else {
-        read_from_server(aoc)
+        read_to_string(path).map_err(|err| Error::io(path, err))
     }
 }
 
-fn display_solution(part: usize, solution: &str) {
-    if solution.contains('\n') {
-        println!("Part {}:\n{}", part, solution);
+/// Read the input from `path` (`-` for standard input) if given, otherwise from the cache, fetching
+/// it from `source` first if it isn't cached and we're not `offline`.
+pub fn read_input<P: AsRef<Path>>(
+    path: Option<P>,
+    cache: &InputCache,
+    source: &mut impl InputSource,
+    year: i32,
+    day: u32,
+    offline: bool,
+    normalise: bool,
+) -> Result<String, Error> {
+    let data = if let Some(path) = &path {
+        read_file(path)?
     } else {
-        println!("Part {}: {}", part, solution);
+        cache.read(year, day, offline, || source.fetch(year, day))?
+    };
+
+    Ok(if normalise {
+        normalise_input(&data)
+    } else {
+        data
+    })
+}
+
+fn display_solution(part: usize, answer: &Answer) {
+    if answer.is_multi_line() {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
     }
 }
 
@@ -72,6 +213,16 @@ enum TimeUnit {
 }
 
 impl TimeUnit {
+    fn for_duration(duration: Duration) -> Self {
+        if duration.as_millis() < 3 {
+            TimeUnit::Microseconds
+        } else if duration.as_secs() < 3 {
+            TimeUnit::Milliseconds
+        } else {
+            TimeUnit::Seconds
+        }
+    }
+
     fn name(&self) -> &'static str {
         match self {
             TimeUnit::Microseconds => "µs",
@@ -80,7 +231,7 @@ impl TimeUnit {
         }
     }
 
-    fn from_duration(&self, duration: Duration) -> u128 {
+    fn convert(&self, duration: Duration) -> u128 {
         match self {
             TimeUnit::Microseconds => duration.as_micros(),
             TimeUnit::Milliseconds => duration.as_millis(),
@@ -89,66 +240,368 @@ impl TimeUnit {
     }
 }
 
-pub fn solve<S: Solver>(data: String, aoc: &mut Aoc, submit: Option<Part>) -> Result<(), Error> {
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let unit = TimeUnit::for_duration(duration);
+    format!("{}{}", unit.convert(duration), unit.name())
+}
+
+/// The answers and timings from running a day. Parts that weren't run have
+/// no answer or time, and neither part has its own time if the day solves
+/// them together.
+pub struct Solution {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    pub parse_time: Duration,
+    pub part_one_time: Option<Duration>,
+    pub part_two_time: Option<Duration>,
+    pub solve_time: Duration,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
     let start = Instant::now();
-    let problem = S::parse_input(data)?;
-    let start_solve = Instant::now();
-    let (part_one, part_two) = S::solve(problem);
-    let complete = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
 
-    if let Some(solution) = part_one {
-        display_solution(1, &solution);
+/// Parse the input and solve either the given part or, if `part` is `None`,
+/// both parts, timing each step separately.
+pub fn run<S: Solver>(data: String, part: Option<Part>) -> Result<Solution, Error> {
+    let (problem, parse_time) = timed(|| S::parse_input(data));
+    let problem = problem?;
+    let wanted = |wanted| part.is_none_or(|part| part == wanted);
 
-        if submit == Some(Part::One) {
-            let outcome = (*aoc).submit(&solution)?;
-            println!("{}", outcome);
-        }
+    if S::COMBINED {
+        let ((part_one, part_two), solve_time) = timed(|| S::solve(problem));
+        return Ok(Solution {
+            part_one: part_one.filter(|_| wanted(Part::One)),
+            part_two: part_two.filter(|_| wanted(Part::Two)),
+            parse_time,
+            part_one_time: None,
+            part_two_time: None,
+            solve_time,
+        });
     }
 
-    if let Some(solution) = part_two {
-        display_solution(2, &solution);
+    let (part_one, part_one_time) = if wanted(Part::One) {
+        let (answer, time) = timed(|| S::part_one(&problem));
+        (answer, Some(time))
+    } else {
+        (None, None)
+    };
+
+    let (part_two, part_two_time) = if wanted(Part::Two) {
+        let (answer, time) = timed(|| S::part_two(&problem));
+        (answer, Some(time))
+    } else {
+        (None, None)
+    };
+
+    Ok(Solution {
+        part_one,
+        part_two,
+        parse_time,
+        part_one_time,
+        part_two_time,
+        solve_time: part_one_time.unwrap_or_default() + part_two_time.unwrap_or_default(),
+    })
+}
+
+/// A day's solver as registered with its year's `solvers!`.
+pub struct Registration {
+    pub day: u32,
+    pub title: &'static str,
+    pub run: fn(String, Option<Part>) -> Result<Solution, Error>,
+}
+
+/// Register each day's solver, in order. The modules are declared as plain
+/// `mod` items beside this, where rustfmt can find them.
+macro_rules! solvers {
+    ($($day:literal => $module:ident),* $(,)?) => {
+        /// Every implemented day, in order.
+        pub const SOLVERS: &[crate::Registration] = &[
+            $(crate::Registration {
+                day: $day,
+                title: <$module::Solver as crate::Solver>::TITLE,
+                run: crate::run::<$module::Solver>,
+            }),*
+        ];
+    };
+}
+
+mod year2025;
+
+/// One year's event and the days solved so far.
+pub struct Event {
+    pub year: i32,
+    /// The number of puzzles in the year.
+    pub last_day: u32,
+    pub solvers: &'static [Registration],
+}
+
+/// Every supported event, oldest first.
+pub const EVENTS: &[Event] = &[Event {
+    year: 2025,
+    last_day: year2025::LAST_DAY,
+    solvers: year2025::SOLVERS,
+}];
 
-        if submit == Some(Part::Two) {
-            let outcome = aoc.submit(&solution)?;
-            println!("{}", outcome);
+impl Event {
+    pub fn find_solver(&self, day: u32) -> Result<&'static Registration, Error> {
+        if let Some(solver) = self.solvers.iter().find(|solver| solver.day == day) {
+            Ok(solver)
+        } else if (1..=self.last_day).contains(&day) {
+            Err(Error::Invalid(format!("Day {} isn't implemented yet", day)))
+        } else {
+            Err(Error::Invalid(format!("Invalid day {}", day)))
         }
     }
+}
 
-    let parse_time = start_solve - start;
-    let solve_time = complete - start_solve;
-    let total_time = complete - start;
-    let unit = if total_time.as_millis() < 3 {
-        TimeUnit::Microseconds
-    } else if total_time.as_secs() < 3 {
-        TimeUnit::Milliseconds
-    } else {
-        TimeUnit::Seconds
-    };
+pub fn find_event(year: i32) -> Result<&'static Event, Error> {
+    EVENTS
+        .iter()
+        .find(|event| event.year == year)
+        .ok_or_else(|| Error::Invalid(format!("No solvers for {}", year)))
+}
+
+/// The most recent event, used when no year is given.
+pub fn latest_year() -> i32 {
+    EVENTS.last().map(|event| event.year).unwrap_or_default()
+}
+
+pub fn run_day(year: i32, day: u32, data: String, part: Option<Part>) -> Result<Solution, Error> {
+    (find_event(year)?.find_solver(day)?.run)(data, part)
+}
+
+pub fn solve_day(year: i32, day: u32, data: String, part: Option<Part>) -> Result<Solution, Error> {
+    let solution = run_day(year, day, data, part)?;
+
+    if let Some(answer) = &solution.part_one {
+        display_solution(1, answer);
+    }
+
+    if let Some(answer) = &solution.part_two {
+        display_solution(2, answer);
+    }
+
+    let total_time = solution.parse_time + solution.solve_time;
+    let unit = TimeUnit::for_duration(total_time);
+    let mut phases = vec![(solution.parse_time, "parse")];
+    if let Some(time) = solution.part_one_time {
+        phases.push((time, "part 1"));
+    }
+    if let Some(time) = solution.part_two_time {
+        phases.push((time, "part 2"));
+    }
+    if solution.part_one_time.is_none() && solution.part_two_time.is_none() {
+        phases.push((solution.solve_time, "solve"));
+    }
     println!(
-        "Took {}{} ({}{} parse, {}{} solve)",
-        unit.from_duration(total_time),
-        unit.name(),
-        unit.from_duration(parse_time),
-        unit.name(),
-        unit.from_duration(solve_time),
+        "Took {}{} ({})",
+        unit.convert(total_time),
         unit.name(),
+        phases
+            .into_iter()
+            .map(|(time, phase)| format!("{}{} {}", unit.convert(time), unit.name(), phase))
+            .collect::<Vec<_>>()
+            .join(", ")
     );
 
-    Ok(())
-}
-
-pub fn solve_day(day: u32, data: String, aoc: &mut Aoc, submit: Option<Part>) -> Result<(), Error> {
-    match day {
-        1 => solve::<day01::Solver>(data, aoc, submit),
-        2 => solve::<day02::Solver>(data, aoc, submit),
-        3 => solve::<day03::Solver>(data, aoc, submit),
-        4 => solve::<day04::Solver>(data, aoc, submit),
-        5 => solve::<day05::Solver>(data, aoc, submit),
-        6 => solve::<day06::Solver>(data, aoc, submit),
-        7 => solve::<day07::Solver>(data, aoc, submit),
-        8 => solve::<day08::Solver>(data, aoc, submit),
-        9 => solve::<day09::Solver>(data, aoc, submit),
-        10 => solve::<day10::Solver>(data, aoc, submit),
-        _ => Err(failure::err_msg(format!("Invalid day {}", day))),
+    Ok(solution)
+}
+
+/// Submit the answer to one part of a solution, returning the server's
+/// response.
+///
+/// The ledger is checked first so that known-wrong answers are never sent
+/// again, and every submission is recorded in it along with its verdict.
+pub fn submit(
+    year: i32,
+    day: u32,
+    part: Part,
+    solution: &Solution,
+    ledger: &mut Ledger,
+    submitter: &mut impl Submitter,
+) -> Result<String, Error> {
+    let answer = match part {
+        Part::One => &solution.part_one,
+        Part::Two => &solution.part_two,
+    }
+    .as_ref()
+    .ok_or_else(|| Error::Refused(format!("No answer for part {}", part)))?
+    .submission()?;
+
+    for warning in ledger.check(day, part, &answer)? {
+        println!("Warning: {}", warning);
+    }
+
+    let response = submitter.submit(year, day, part, &answer)?;
+    ledger.record(day, part, &answer, Verdict::from_response(&response));
+    ledger.save()?;
+    Ok(response)
+}
+
+/// Run each day in turn, collecting failures (including panics) rather than
+/// stopping at the first one.
+pub fn run_days<T, R, F>(days: &Days, mut read: R, run: F) -> Vec<(u32, Result<T, Error>)>
+where
+    R: FnMut(u32) -> Result<String, Error>,
+    F: Fn(u32, String) -> Result<T, Error>,
+{
+    days.iter()
+        .map(|day| {
+            let result = read(day).and_then(|data| {
+                catch_unwind(AssertUnwindSafe(|| run(day, data)))
+                    .unwrap_or_else(|_| Err(Error::Solver("panicked".to_string())))
+            });
+            (day, result)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The message from parsing `data`, which must fail.
+    pub(crate) fn parse_error<S: Solver>(data: &str) -> String {
+        match S::parse_input(data.to_string()) {
+            Ok(_) => panic!("parsed {:?}", data),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+        let expected = read_examples(examples).unwrap();
+        assert!(!expected.is_empty());
+
+        // Days whose examples need different parameters from the real input,
+        // and which test them themselves.
+        const NO_FIXTURES: &[(i32, u32)] = &[(2025, 8)];
+        let missing: Vec<_> = EVENTS
+            .iter()
+            .flat_map(|event| event.solvers.iter().map(|solver| (event.year, solver.day)))
+            .filter(|day| !NO_FIXTURES.contains(day))
+            .filter(|&(year, day)| {
+                !expected
+                    .iter()
+                    .any(|expected| expected.year == year && expected.day == day)
+            })
+            .map(|(year, day)| format!("{} day {}", year, day))
+            .collect();
+        assert!(missing.is_empty(), "No fixtures for {}", missing.join(", "));
+
+        let failures: Vec<_> = verify(&expected, |path| Ok(read_to_string(path)?))
+            .into_iter()
+            .filter(|(_, outcome)| !outcome.is_correct())
+            .map(|(expected, outcome)| {
+                format!(
+                    "{} day {} part {} ({}): {}",
+                    expected.year,
+                    expected.day,
+                    expected.part,
+                    expected.input.display(),
+                    outcome
+                )
+            })
+            .collect();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_normalise_input() {
+        assert_eq!(normalise_input("a\nb\n"), "a\nb\n");
+        assert_eq!(normalise_input("a\nb"), "a\nb\n");
+        assert_eq!(normalise_input("\u{feff}a\r\nb\r\n\r\n\n"), "a\nb\n");
+        assert_eq!(normalise_input(" a \n"), " a \n");
+        assert_eq!(normalise_input("\n\n"), "");
+    }
+
+    #[test]
+    fn test_examples_normalised() {
+        let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+        let expected = read_examples(examples).unwrap();
+
+        type Mangle = fn(&str) -> String;
+        let mangles: [(&str, Mangle); 4] = [
+            ("CRLF", |data| data.replace('\n', "\r\n")),
+            ("no final newline", |data| {
+                data.trim_end_matches('\n').to_string()
+            }),
+            ("extra newlines", |data| format!("{}\n\n", data)),
+            ("BOM", |data| format!("\u{feff}{}", data)),
+        ];
+
+        for (name, mangle) in mangles {
+            let failures: Vec<_> = verify(&expected, |path| {
+                Ok(normalise_input(&mangle(&read_to_string(path)?)))
+            })
+            .into_iter()
+            .filter(|(_, outcome)| !outcome.is_correct())
+            .map(|(expected, outcome)| {
+                format!(
+                    "{} day {} part {} ({}): {}",
+                    expected.year,
+                    expected.day,
+                    expected.part,
+                    expected.input.display(),
+                    outcome
+                )
+            })
+            .collect();
+
+            assert!(failures.is_empty(), "{}:\n{}", name, failures.join("\n"));
+        }
+    }
+
+    #[test]
+    fn test_days() {
+        let days: Days = "8,1-3,2".parse().unwrap();
+        assert_eq!(days.iter().collect::<Vec<_>>(), [1, 2, 3, 8]);
+        assert!("0".parse::<Days>().is_err());
+        assert!("1-4000000000".parse::<Days>().is_err());
+        assert!("3-1".parse::<Days>().is_err());
+
+        assert!("1-12".parse::<Days>().unwrap().in_event(2025).is_ok());
+        assert_eq!(
+            "1-25"
+                .parse::<Days>()
+                .unwrap()
+                .in_event(2025)
+                .unwrap_err()
+                .to_string(),
+            "Invalid day 13"
+        );
+    }
+
+    #[test]
+    fn test_find_solver() {
+        assert!(EVENTS.windows(2).all(|pair| pair[0].year < pair[1].year));
+        for event in EVENTS {
+            assert!(
+                event
+                    .solvers
+                    .windows(2)
+                    .all(|pair| pair[0].day < pair[1].day)
+            );
+        }
+
+        let event = find_event(2025).unwrap();
+        assert_eq!(event.find_solver(1).unwrap().title, "Secret Entrance");
+        assert_eq!(
+            event.find_solver(12).err().unwrap().to_string(),
+            "Day 12 isn't implemented yet"
+        );
+        assert_eq!(
+            event.find_solver(26).err().unwrap().to_string(),
+            "Invalid day 26"
+        );
+        assert_eq!(
+            find_event(2014).err().unwrap().to_string(),
+            "No solvers for 2014"
+        );
     }
 }
@@ -1,33 +1,378 @@
-use aocf::Aoc;
-use failure::Error;
-
-use std::path::PathBuf;
+use std::env::args_os;
+use std::ffi::OsString;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::process::exit;
 use structopt::StructOpt;
 
-use aoc2025::{Part, read_input, solve_day};
+use aoc2025::{
+    AocServer, Days, Error, InputCache, InputSource, Ledger, Output, Part, bench_day,
+    display_benchmarks, display_history, display_solutions, find_event, latest_year, new_day,
+    normalise_input, read_answers, read_file, read_input, run_day, run_days, solve_day, submit,
+    verify,
+};
 
 #[derive(StructOpt, Debug)]
 struct Opt {
-    day: u32,
-    input: Option<PathBuf>,
+    /// Event to use, defaulting to the most recent
+    #[structopt(long, global = true)]
+    year: Option<i32>,
+
+    /// Directory of cached puzzle inputs
+    #[structopt(long, env = "AOC_CACHE_DIR", default_value = "input", global = true)]
+    cache_dir: PathBuf,
+
+    #[structopt(subcommand)]
+    command: Command,
+}
 
+#[derive(StructOpt, Debug)]
+struct InputOpt {
+    /// Only use cached inputs, never the server
     #[structopt(long)]
-    submit: Option<Part>,
+    offline: bool,
+
+    /// Pass inputs to the solvers exactly as read, without stripping a byte
+    /// order mark, converting CRLF or fixing up the final newline
+    #[structopt(long)]
+    no_normalise: bool,
 }
 
-fn main() -> Result<(), Error> {
-    let opt = Opt::from_args();
+#[derive(StructOpt, Debug)]
+struct RunOpt {
+    /// Only run this part
+    #[structopt(long)]
+    part: Option<Part>,
+
+    /// Time parsing and solving over this many runs instead of once
+    #[structopt(long)]
+    bench: Option<NonZeroU32>,
+
+    /// Output format: text, json or csv
+    #[structopt(long, default_value = "text")]
+    output: Output,
+
+    #[structopt(flatten)]
+    input: InputOpt,
+}
+
+#[derive(StructOpt, Debug)]
+enum Command {
+    /// Solve a single day, which is also what a bare `<day>` does
+    Solve {
+        day: u32,
+        /// Input file to solve instead of the cached input, or `-` for
+        /// standard input
+        input: Option<PathBuf>,
+
+        /// Read the input from standard input, the same as passing `-`
+        #[structopt(long)]
+        stdin: bool,
+
+        /// Submit the answer to this part
+        #[structopt(long)]
+        submit: Option<Part>,
+
+        #[structopt(flatten)]
+        run: RunOpt,
+    },
+    /// Run every implemented day, or just some of them
+    All {
+        /// Days to run, e.g. `1-5,8`
+        #[structopt(long)]
+        days: Option<Days>,
+
+        #[structopt(flatten)]
+        run: RunOpt,
+    },
+    /// Check the answers in a file of expected answers
+    Verify {
+        /// File of expected answers
+        #[structopt(long, default_value = "answers.txt")]
+        answers: PathBuf,
+
+        /// Pass inputs to the solvers exactly as read
+        #[structopt(long)]
+        no_normalise: bool,
+    },
+    /// Download inputs into the cache
+    Fetch {
+        /// Days to fetch, defaulting to every implemented day
+        #[structopt(long)]
+        days: Option<Days>,
+
+        /// Fetch inputs again even if they're already cached
+        #[structopt(long)]
+        force: bool,
+    },
+    /// List the cached inputs
+    List,
+    /// Remove cached inputs
+    Clear {
+        /// Days to remove, defaulting to all of them
+        #[structopt(long)]
+        days: Option<Days>,
+    },
+    /// Show previous submissions
+    History {
+        /// Days to show, defaulting to every day submitted
+        #[structopt(long)]
+        days: Option<Days>,
+    },
+    /// List the implemented days
+    Days,
+    /// Start a new day from the template
+    New { day: u32 },
+}
+
+/// The command line, with `solve` added in front of a bare day so that
+/// `aoc2025 <day> [input]` still works.
+fn args() -> Vec<OsString> {
+    let mut args: Vec<OsString> = args_os().collect();
+
+    let mut index = 1;
+    while let Some(arg) = args.get(index).and_then(|arg| arg.to_str()) {
+        match arg {
+            "--year" | "--cache-dir" | "--part" | "--submit" | "--bench" | "--output" => index += 2,
+            _ if arg.starts_with('-') => index += 1,
+            _ => break,
+        }
+    }
+
+    let day = args.get(index).and_then(|arg| arg.to_str());
+    if day.is_some_and(|day| day.parse::<u32>().is_ok()) {
+        args.insert(1, "solve".into());
+    }
+    args
+}
+
+fn main() {
+    if let Err(err) = run(Opt::from_iter(args())) {
+        report(&err);
+        exit(1);
+    }
+}
+
+fn report(err: &Error) {
+    eprintln!("Error: {}", err);
+
+    let hint = match err {
+        Error::Network(_) => Some("check the session cookie in .aocf, or use --offline"),
+        Error::Parse { path: None, .. } => Some("check the input is for the right day and year"),
+        _ => None,
+    };
+    if let Some(hint) = hint {
+        eprintln!("Hint: {}", hint);
+    }
+}
+
+fn run(opt: Opt) -> Result<(), Error> {
+    let cache = InputCache::new(&opt.cache_dir);
+    let mut server = AocServer;
+    let year = opt.year.unwrap_or_else(latest_year);
+
+    match opt.command {
+        Command::Solve {
+            day,
+            mut input,
+            stdin,
+            submit: submit_part,
+            run,
+        } => {
+            if stdin {
+                if input.is_some() {
+                    return Err(Error::invalid("--stdin can't be used with an input file"));
+                }
+                input = Some(PathBuf::from("-"));
+            }
+            if submit_part.is_some() && (run.bench.is_some() || run.output != Output::Text) {
+                return Err(Error::invalid(
+                    "--submit can't be used with --bench or structured output",
+                ));
+            }
+
+            // Check the day before reading its input, which might fetch it.
+            find_event(year)?.find_solver(day)?;
+            let data = read_input(
+                input.as_ref(),
+                &cache,
+                &mut server,
+                year,
+                day,
+                run.input.offline,
+                !run.input.no_normalise,
+            )?;
+
+            if let Some(runs) = run.bench {
+                let benchmark = bench_day(year, day, &data, run.part, runs)?;
+                display_benchmarks(&[(day, Ok(benchmark))], run.output)?;
+            } else if run.output == Output::Text {
+                let solution = solve_day(year, day, data, run.part)?;
+
+                if let Some(part) = submit_part {
+                    let mut ledger = Ledger::load(cache.ledger_path(year))?;
+                    println!(
+                        "{}",
+                        submit(year, day, part, &solution, &mut ledger, &mut server)?
+                    );
+                }
+            } else {
+                let results = [(day, run_day(year, day, data, run.part))];
+                display_solutions(&results, run.output)?;
+                let [(_, result)] = results;
+                result?;
+            }
+        }
+        Command::All { days, run } => {
+            let days = match days {
+                Some(days) => days.in_event(year)?,
+                None => Days::all(year)?,
+            };
+            let read = |day| {
+                read_input(
+                    None::<PathBuf>,
+                    &cache,
+                    &mut server,
+                    year,
+                    day,
+                    run.input.offline,
+                    !run.input.no_normalise,
+                )
+            };
+
+            let failures = if let Some(runs) = run.bench {
+                let results = run_days(&days, read, |day, data| {
+                    bench_day(year, day, &data, run.part, runs)
+                });
+                display_benchmarks(&results, run.output)?;
+                results.iter().filter(|(_, result)| result.is_err()).count()
+            } else {
+                let results = run_days(&days, read, |day, data| run_day(year, day, data, run.part));
+                display_solutions(&results, run.output)?;
+                results.iter().filter(|(_, result)| result.is_err()).count()
+            };
+
+            if failures > 0 {
+                return Err(Error::invalid(format!("{} day(s) failed", failures)));
+            }
+        }
+        Command::Verify {
+            answers,
+            no_normalise,
+        } => {
+            let expected = read_answers(&answers)?;
+            if expected.is_empty() {
+                return Err(Error::invalid(format!(
+                    "No answers to check in {}",
+                    answers.display()
+                )));
+            }
+            let outcomes = verify(&expected, |path| {
+                let data = read_file(path)?;
+                Ok(if no_normalise {
+                    data
+                } else {
+                    normalise_input(&data)
+                })
+            });
+
+            for (expected, outcome) in outcomes.iter() {
+                println!(
+                    "{} day {} part {} ({}): {}",
+                    expected.year,
+                    expected.day,
+                    expected.part,
+                    expected.input.display(),
+                    outcome
+                );
+            }
+
+            let failures = outcomes
+                .iter()
+                .filter(|(_, outcome)| !outcome.is_correct())
+                .count();
+            if failures > 0 {
+                return Err(Error::invalid(format!(
+                    "{} of {} answers incorrect",
+                    failures,
+                    outcomes.len()
+                )));
+            }
+        }
+        Command::Fetch { days, force } => {
+            let mut failures = 0;
+            let days = match days {
+                Some(days) => days.in_event(year)?,
+                None => Days::all(year)?,
+            };
+            for day in days.iter() {
+                if !force && cache.get(year, day)?.is_some() {
+                    println!("Day {}: already cached", day);
+                    continue;
+                }
 
-    let mut aoc = Aoc::new()
-        .parse_cli(false)
-        .year(Some(2025))
-        .day(Some(opt.day))
-        .init()?;
+                match server
+                    .fetch(year, day)
+                    .and_then(|data| cache.store(year, day, &data))
+                {
+                    Ok(()) => println!("Day {}: fetched", day),
+                    Err(err) => {
+                        println!("Day {}: Error: {}", day, err);
+                        failures += 1;
+                    }
+                }
+            }
 
-    let data = read_input(opt.input, &mut aoc)
-        .map_err(|err| failure::err_msg(format!("Failed to read input: {}", err)))?;
+            if failures > 0 {
+                return Err(Error::invalid(format!("{} day(s) failed", failures)));
+            }
+        }
+        Command::List => {
+            for day in cache.list(year)? {
+                println!("Day {}: {}", day, cache.path(year, day).display());
+            }
+        }
+        Command::Clear { days } => {
+            let days = match days {
+                Some(days) => days.in_event(year)?.iter().collect(),
+                None => cache.list(year)?,
+            };
 
-    solve_day(opt.day, data, &mut aoc, opt.submit)?;
+            let mut removed = 0;
+            for day in days {
+                if cache.remove(year, day)? {
+                    removed += 1;
+                }
+            }
+            println!(
+                "Removed {} cached input(s) from {}",
+                removed,
+                cache.dir().display()
+            );
+        }
+        Command::History { days } => {
+            let ledger = Ledger::load(cache.ledger_path(year))?;
+            let days = match days {
+                Some(days) => days.in_event(year)?.iter().collect(),
+                None => ledger.days(),
+            };
+            display_history(&ledger, &days);
+        }
+        Command::Days => {
+            let event = find_event(year)?;
+            for day in 1..=event.last_day {
+                match event.find_solver(day) {
+                    Ok(solver) => println!("Day {}: {}", day, solver.title),
+                    Err(_) => println!("Day {}: not implemented", day),
+                }
+            }
+        }
+        Command::New { day } => {
+            for path in new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,217 @@
+use crate::Error;
+use crate::{Answer, Solution, format_duration};
+use serde::Serialize;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum Output {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Output {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Output::Text),
+            "json" => Ok(Output::Json),
+            "csv" => Ok(Output::Csv),
+            _ => Err(format!("Unknown output format {}", s)),
+        }
+    }
+}
+
+/// The outcome of running one day, in a form suitable for machine-readable
+/// output.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    pub parse_time_ns: Option<u128>,
+    pub part_one_time_ns: Option<u128>,
+    pub part_two_time_ns: Option<u128>,
+    pub solve_time_ns: Option<u128>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(day: u32, result: &Result<Solution, Error>) -> Self {
+        match result {
+            Ok(solution) => Record {
+                day,
+                part_one: solution.part_one.clone(),
+                part_two: solution.part_two.clone(),
+                parse_time_ns: Some(solution.parse_time.as_nanos()),
+                part_one_time_ns: solution.part_one_time.map(|time| time.as_nanos()),
+                part_two_time_ns: solution.part_two_time.map(|time| time.as_nanos()),
+                solve_time_ns: Some(solution.solve_time.as_nanos()),
+                error: None,
+            },
+            Err(err) => Record {
+                day,
+                part_one: None,
+                part_two: None,
+                parse_time_ns: None,
+                part_one_time_ns: None,
+                part_two_time_ns: None,
+                solve_time_ns: None,
+                error: Some(err.to_string()),
+            },
+        }
+    }
+
+    fn csv_fields(&self) -> [String; 8] {
+        let time = |time: Option<u128>| time.map(|time| time.to_string()).unwrap_or_default();
+        [
+            self.day.to_string(),
+            self.part_one
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default(),
+            self.part_two
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default(),
+            time(self.parse_time_ns),
+            time(self.part_one_time_ns),
+            time(self.part_two_time_ns),
+            time(self.solve_time_ns),
+            self.error.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+pub(crate) fn csv_row<S: AsRef<str>>(fields: &[S]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            let field = field.as_ref();
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn display_solutions(
+    results: &[(u32, Result<Solution, Error>)],
+    output: Output,
+) -> Result<(), Error> {
+    let records = results
+        .iter()
+        .map(|(day, result)| Record::new(*day, result));
+
+    match output {
+        Output::Text => display_summary(results),
+        Output::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&records.collect::<Vec<_>>())?
+        ),
+        Output::Csv => {
+            println!(
+                "{}",
+                csv_row(&[
+                    "day",
+                    "part_one",
+                    "part_two",
+                    "parse_time_ns",
+                    "part_one_time_ns",
+                    "part_two_time_ns",
+                    "solve_time_ns",
+                    "error"
+                ])
+            );
+            for record in records {
+                println!("{}", csv_row(&record.csv_fields()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn summary_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(answer) if answer.is_multi_line() => "<multi-line>".to_string(),
+        Some(answer) => answer.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn display_summary(results: &[(u32, Result<Solution, Error>)]) {
+    let header = [
+        "Day",
+        "Part 1",
+        "Part 2",
+        "Parse",
+        "Part 1 time",
+        "Part 2 time",
+        "Solve",
+    ]
+    .map(String::from);
+    let time = |time: Option<Duration>| time.map(format_duration).unwrap_or("-".to_string());
+    let rows: Vec<(u32, Result<[String; 7], &Error>)> = results
+        .iter()
+        .map(|(day, result)| {
+            let row = result.as_ref().map(|solution| {
+                [
+                    day.to_string(),
+                    summary_answer(&solution.part_one),
+                    summary_answer(&solution.part_two),
+                    format_duration(solution.parse_time),
+                    time(solution.part_one_time),
+                    time(solution.part_two_time),
+                    format_duration(solution.solve_time),
+                ]
+            });
+            (*day, row)
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|title| title.chars().count());
+    for row in rows.iter().filter_map(|(_, row)| row.as_ref().ok()) {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: &[String; 7]| {
+        row.iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!("{}", format_row(&header));
+    for (day, row) in rows.iter() {
+        match row {
+            Ok(row) => println!("{}", format_row(row)),
+            Err(err) => println!("{:<width$}  Error: {}", day, err, width = widths[0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_csv_row() {
+        assert_eq!(csv_row(&["1", "2", ""]), "1,2,");
+        assert_eq!(
+            csv_row(&["a,b", "say \"hi\"", "x\ny"]),
+            "\"a,b\",\"say \"\"hi\"\"\",\"x\ny\""
+        );
+    }
+}
@@ -1,9 +1,11 @@
 #![allow(unused)]
+use crate::Error;
 use nom::{
     IResult, Parser,
     bytes::complete::{tag, take_while1},
-    character::complete::digit1,
-    combinator::{map_res, opt, recognize},
+    character::complete::{digit1, newline},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::ErrorKind,
     sequence::pair,
 };
 use std::str::FromStr;
@@ -21,3 +23,101 @@ pub fn signed(input: &str) -> IResult<&str, i64> {
     })
     .parse(input)
 }
+
+/// One or more newline-terminated items, up to the end of the input or a
+/// blank line.
+///
+/// Unlike `many1(terminated(item, newline))`, a line that doesn't parse is an
+/// error at the point it went wrong, rather than the end of the list.
+pub fn lines1<'a, O, P>(mut item: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    P: Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+{
+    move |mut input: &'a str| {
+        let mut items = vec![];
+        loop {
+            let (rest, output) = item.parse(input)?;
+            let (rest, _) = newline(rest)?;
+            items.push(output);
+            input = rest;
+
+            if input.is_empty() || input.starts_with('\n') {
+                return Ok((input, items));
+            }
+        }
+    }
+}
+
+/// Convert the error of a nom parser run over `input` into a parse error at
+/// the position where it failed.
+pub fn nom_error(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Error {
+    match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => {
+            let message = match err.code {
+                ErrorKind::Eof => "Unexpected trailing input".to_string(),
+                ErrorKind::Char => "Unexpected character".to_string(),
+                ErrorKind::Tag => "Unexpected input".to_string(),
+                ErrorKind::MapRes => "Invalid value".to_string(),
+                code => format!("Failed to parse input ({})", code.description()),
+            };
+            Error::parse_at(input, err.input, message)
+        }
+        nom::Err::Incomplete(_) => {
+            Error::parse_at(input, &input[input.len()..], "Unexpected end of input")
+        }
+    }
+}
+
+/// Run `parser` over the whole of `input`.
+pub fn parse_all<'a, O, P>(parser: P, input: &'a str) -> Result<O, Error>
+where
+    P: Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+{
+    all_consuming(parser)
+        .parse(input)
+        .map(|(_, output)| output)
+        .map_err(|err| nom_error(input, err))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::bytes::complete::tag;
+
+    fn pair(input: &str) -> IResult<&str, (u64, u64)> {
+        (unsigned, tag(","), unsigned)
+            .map(|(a, _, b)| (a, b))
+            .parse(input)
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(
+            parse_all(lines1(pair), "1,2\n3,4\n").unwrap(),
+            vec![(1, 2), (3, 4)]
+        );
+
+        assert_eq!(
+            parse_all(lines1(pair), "1,2\n3;4\n5,6\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 2: Unexpected input\n  3;4\n   ^"
+        );
+        assert_eq!(
+            parse_all(lines1(pair), "1,2\n3,4x\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 4: Unexpected character\n  3,4x\n     ^"
+        );
+        assert_eq!(
+            parse_all(lines1(pair), "1,2\n\n").unwrap_err().to_string(),
+            "line 2, column 1: Unexpected trailing input"
+        );
+        assert_eq!(
+            parse_all(lines1(pair), "1,2\n3,99999999999999999999\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 3: Invalid value\n  3,99999999999999999999\n    ^"
+        );
+    }
+}
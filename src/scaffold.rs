@@ -0,0 +1,183 @@
+use crate::Error;
+use crate::find_event;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+// Build the template as it is, so that it can't drift from the `Solver`
+// trait; `{{day}}` is only ever inside a string.
+#[cfg(test)]
+#[path = "../templates/day.rs"]
+mod template;
+
+/// Declare the `day` module and add its entry to the `solvers!` invocation in
+/// the source of a year's module, keeping the days in order.
+fn register(source: &str, day: u32) -> Result<String, Error> {
+    let source = add_to_registry(source, day)?;
+
+    let declared = |line: &str| {
+        line.strip_prefix("mod day")
+            .and_then(|line| line.strip_suffix(';'))
+            .and_then(|line| line.parse::<u32>().ok())
+    };
+    let mut lines: Vec<_> = source.lines().map(String::from).collect();
+    let modules: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| declared(line).map(|declared| (index, declared)))
+        .collect();
+    let index = match modules.iter().find(|&&(_, declared)| declared > day) {
+        Some(&(index, _)) => index,
+        None => modules.last().map(|&(index, _)| index + 1).unwrap_or(0),
+    };
+    if modules.is_empty() {
+        lines.insert(0, String::new());
+    }
+    lines.insert(index, format!("mod day{:02};", day));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_to_registry(source: &str, day: u32) -> Result<String, Error> {
+    let start = source
+        .find("\nsolvers! {\n")
+        .ok_or_else(|| Error::invalid("Couldn't find the solvers! registry"))?
+        + "\nsolvers! {\n".len();
+    let end = start
+        + source[start..]
+            .find("}\n")
+            .ok_or_else(|| Error::invalid("Couldn't find the end of the solvers! registry"))?;
+
+    let mut entries = vec![];
+    for line in source[start..end].lines() {
+        let registered = line
+            .split_once("=>")
+            .and_then(|(registered, _)| registered.trim().parse::<u32>().ok())
+            .ok_or_else(|| Error::invalid(format!("Unexpected registry entry {}", line.trim())))?;
+        if registered == day {
+            return Err(Error::invalid(format!("Day {} is already registered", day)));
+        }
+        entries.push((registered, line.to_string()));
+    }
+
+    entries.push((day, format!("    {} => day{:02},", day, day)));
+    entries.sort_by_key(|(day, _)| *day);
+
+    let mut registry = String::new();
+    for (_, line) in entries {
+        registry.push_str(&line);
+        registry.push('\n');
+    }
+
+    Ok(format!(
+        "{}{}{}",
+        &source[..start],
+        registry,
+        &source[end..]
+    ))
+}
+
+/// Generate the module for a new day from the template, register it and add
+/// an empty example, returning the files written. Nothing is written if the
+/// day already exists.
+pub fn new_day(root: &Path, year: i32, day: u32) -> Result<Vec<PathBuf>, Error> {
+    if !(1..=find_event(year)?.last_day).contains(&day) {
+        return Err(Error::invalid(format!("Invalid day {}", day)));
+    }
+
+    let year_module = format!("year{}", year);
+    let module = root
+        .join("src")
+        .join(&year_module)
+        .join(format!("day{:02}.rs", day));
+    let examples = root
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("day{:02}", day));
+    for path in [&module, &examples] {
+        if path.exists() {
+            return Err(Error::invalid(format!(
+                "Refusing to overwrite {}",
+                path.display()
+            )));
+        }
+    }
+
+    let registry = root.join("src").join(format!("{}.rs", year_module));
+    let source = read_to_string(&registry).map_err(|err| Error::io(&registry, err))?;
+    let source = register(&source, day)?;
+
+    let write_file =
+        |path: &Path, data: &str| write(path, data).map_err(|err| Error::io(path, err));
+    let create_dir = |path: &Path| create_dir_all(path).map_err(|err| Error::io(path, err));
+
+    create_dir(&root.join("src").join(&year_module))?;
+    write_file(&module, &TEMPLATE.replace("{{day}}", &day.to_string()))?;
+    write_file(&registry, &source)?;
+    create_dir(&examples)?;
+    let fixture = examples.join("example.txt");
+    let answers = examples.join("example.answers");
+    write_file(&fixture, "")?;
+    write_file(&answers, "")?;
+
+    Ok(vec![module, registry, fixture, answers])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tempfile::tempdir;
+
+    const REGISTRY: &str = "mod day01;\nmod day03;\n\npub const LAST_DAY: u32 = 12;\n\n\
+        solvers! {\n    1 => day01,\n    3 => day03,\n}\n";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            register(REGISTRY, 2).unwrap(),
+            "mod day01;\nmod day02;\nmod day03;\n\npub const LAST_DAY: u32 = 12;\n\n\
+            solvers! {\n    1 => day01,\n    2 => day02,\n    3 => day03,\n}\n"
+        );
+        assert!(
+            register(REGISTRY, 4)
+                .unwrap()
+                .starts_with("mod day01;\nmod day03;\nmod day04;\n\n")
+        );
+        assert_eq!(
+            register("pub const LAST_DAY: u32 = 12;\n\nsolvers! {\n}\n", 1).unwrap(),
+            "mod day01;\n\npub const LAST_DAY: u32 = 12;\n\nsolvers! {\n    1 => day01,\n}\n"
+        );
+        assert!(register(REGISTRY, 3).is_err());
+        assert!(register("pub const LAST_DAY: u32 = 12;\n", 2).is_err());
+    }
+
+    #[test]
+    fn test_template() {
+        let solution = crate::run::<template::Solver>("1\n2\n".to_string(), None).unwrap();
+        assert!(solution.part_one.is_none() && solution.part_two.is_none());
+        assert!(crate::run::<template::Solver>("x\n".to_string(), None).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = tempdir().unwrap();
+        create_dir_all(dir.path().join("src")).unwrap();
+        write(dir.path().join("src").join("year2025.rs"), REGISTRY).unwrap();
+
+        let written = new_day(dir.path(), 2025, 2).unwrap();
+        assert_eq!(written.len(), 4);
+        let module = read_to_string(dir.path().join("src/year2025/day02.rs")).unwrap();
+        assert!(module.contains("const TITLE: &str = \"Day 2\";"));
+        assert!(
+            dir.path()
+                .join("examples/2025/day02/example.answers")
+                .exists()
+        );
+
+        let err = new_day(dir.path(), 2025, 2).unwrap_err();
+        assert!(err.to_string().starts_with("Refusing to overwrite"));
+        assert!(new_day(dir.path(), 2025, 13).is_err());
+        assert!(new_day(dir.path(), 2014, 1).is_err());
+    }
+}
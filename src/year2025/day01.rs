@@ -1,17 +1,15 @@
 use std::fmt::Display;
 
-use failure::Error;
+use crate::Answer;
+use crate::Error;
 use parse::parse_input;
 
 mod parse {
-    use crate::parsers::unsigned;
-    use failure::{Error, err_msg};
+    use crate::Error;
+    use crate::parsers::{lines1, parse_all, unsigned};
     use nom::branch::alt;
     use nom::bytes::complete::tag;
-    use nom::character::complete::newline;
-    use nom::combinator::{all_consuming, map, value};
-    use nom::multi::many1;
-    use nom::sequence::terminated;
+    use nom::combinator::{map, value};
     use nom::{IResult, Parser};
 
     use super::{Direction, Rotation};
@@ -33,13 +31,7 @@ mod parse {
     }
 
     pub(super) fn parse_input(s: &str) -> Result<Box<[Rotation]>, Error> {
-        all_consuming(map(
-            many1(terminated(rotation, newline)),
-            Vec::into_boxed_slice,
-        ))
-        .parse(s)
-        .map_err(|err| err_msg(format!("Failed to parse input: {}", err)))
-        .map(|(_, rotations)| rotations)
+        parse_all(map(lines1(rotation), Vec::into_boxed_slice), s)
     }
 }
 
@@ -120,17 +112,19 @@ fn count_total_zeroes(rotations: &[Rotation]) -> usize {
 
 pub struct Solver {}
 
-impl super::Solver for Solver {
+impl crate::Solver for Solver {
+    const TITLE: &str = "Secret Entrance";
     type Problem = Box<[Rotation]>;
 
     fn parse_input(data: String) -> Result<Self::Problem, Error> {
         parse_input(&data)
     }
 
-    fn solve(rotations: Self::Problem) -> (Option<String>, Option<String>) {
-        let part1 = count_zeroes(&rotations);
-        let part2 = count_total_zeroes(&rotations);
+    fn part_one(rotations: &Self::Problem) -> Option<Answer> {
+        Some(count_zeroes(rotations).into())
+    }
 
-        (Some(part1.to_string()), Some(part2.to_string()))
+    fn part_two(rotations: &Self::Problem) -> Option<Answer> {
+        Some(count_total_zeroes(rotations).into())
     }
 }
@@ -1,20 +1,21 @@
 use std::collections::HashSet;
 
-use failure::Error;
+use crate::Answer;
+use crate::Error;
 use parse::parse_input;
 use std::ops::RangeInclusive;
 
 mod parse {
-    use failure::{Error, err_msg};
+    use crate::Error;
     use nom::bytes::complete::tag;
     use nom::character::complete::newline;
-    use nom::combinator::{all_consuming, map};
+    use nom::combinator::map;
     use nom::multi::separated_list1;
     use nom::sequence::{separated_pair, terminated};
     use nom::{IResult, Parser};
     use std::ops::RangeInclusive;
 
-    use crate::parsers::unsigned;
+    use crate::parsers::{parse_all, unsigned};
 
     fn range(s: &str) -> IResult<&str, RangeInclusive<u64>> {
         map(
@@ -25,13 +26,13 @@ mod parse {
     }
 
     pub(super) fn parse_input(s: &str) -> Result<Box<[RangeInclusive<u64>]>, Error> {
-        all_consuming(map(
-            terminated(separated_list1(tag(","), range), newline),
-            Vec::into_boxed_slice,
-        ))
-        .parse(s)
-        .map_err(|err| err_msg(format!("Failed to parse input: {}", err)))
-        .map(|(_, rotations)| rotations)
+        parse_all(
+            map(
+                terminated(separated_list1(tag(","), range), newline),
+                Vec::into_boxed_slice,
+            ),
+            s,
+        )
     }
 }
 
@@ -45,7 +46,7 @@ fn repeat_num(num: u64, repeats: usize) -> u64 {
 
 fn invalid_ids_in_range(range: RangeInclusive<u64>, repeats: usize) -> impl Iterator<Item = u64> {
     let start_len = range.start().to_string().len();
-    let start_upper = if start_len % repeats == 0 {
+    let start_upper = if start_len.is_multiple_of(repeats) {
         let upper_len = start_len / repeats;
         let offset = 10u64.pow((start_len - upper_len) as u32);
         let upper = range.start() / offset;
@@ -60,7 +61,7 @@ fn invalid_ids_in_range(range: RangeInclusive<u64>, repeats: usize) -> impl Iter
     };
 
     let end_len = range.end().to_string().len();
-    let end_upper = if end_len % repeats == 0 {
+    let end_upper = if end_len.is_multiple_of(repeats) {
         let upper_len = end_len / repeats;
         let offset = 10u64.pow((end_len - upper_len) as u32);
         let upper = range.end() / offset;
@@ -92,7 +93,7 @@ fn sum_simple_invalid_ids(ranges: &[RangeInclusive<u64>]) -> u64 {
 fn is_invalid_id_complex(id: u64) -> bool {
     let id_str = id.to_string();
     (1..=id_str.len() / 2).any(|len| {
-        id_str.len() % len == 0 && {
+        id_str.len().is_multiple_of(len) && {
             let segments = (0..id_str.len() / len)
                 .map(|index| &id_str[index * len..(index + 1) * len])
                 .collect::<Vec<_>>();
@@ -120,17 +121,19 @@ fn sum_complex_invalid_ids(ranges: &[RangeInclusive<u64>]) -> u64 {
 }
 pub struct Solver {}
 
-impl super::Solver for Solver {
+impl crate::Solver for Solver {
+    const TITLE: &str = "Gift Shop";
     type Problem = Box<[RangeInclusive<u64>]>;
 
     fn parse_input(data: String) -> Result<Self::Problem, Error> {
         parse_input(&data)
     }
 
-    fn solve(ranges: Self::Problem) -> (Option<String>, Option<String>) {
-        let part1: u64 = sum_simple_invalid_ids(&ranges);
-        let part2: u64 = sum_complex_invalid_ids(&ranges);
+    fn part_one(ranges: &Self::Problem) -> Option<Answer> {
+        Some(sum_simple_invalid_ids(ranges).into())
+    }
 
-        (Some(part1.to_string()), Some(part2.to_string()))
+    fn part_two(ranges: &Self::Problem) -> Option<Answer> {
+        Some(sum_complex_invalid_ids(ranges).into())
     }
 }
@@ -0,0 +1,113 @@
+use crate::Answer;
+use crate::Error;
+
+fn find_max_joltage_for_bank(bank: &[u8], num_batteries: usize) -> u64 {
+    let mut best_idxs: Vec<_> = (0..num_batteries).collect();
+    for idx in 1..bank.len() {
+        for best_idx in 0..num_batteries {
+            if best_idxs[best_idx] == idx {
+                break;
+            }
+
+            let remaining_best = num_batteries - best_idx;
+            if bank[idx] > bank[best_idxs[best_idx]] && idx + remaining_best - 1 < bank.len() {
+                for offset in 0..num_batteries - best_idx {
+                    best_idxs[best_idx + offset] = idx + offset;
+                }
+                break;
+            }
+        }
+    }
+
+    best_idxs
+        .iter()
+        .rev()
+        .cloned()
+        .zip(0..)
+        .map(|(idx, pow)| bank[idx] as u64 * 10u64.pow(pow))
+        .sum()
+}
+
+/// The most batteries either part turns on in a bank.
+const MAX_BATTERIES: usize = 12;
+
+fn find_max_joltage(banks: &[Box<[u8]>], num_batteries: usize) -> u64 {
+    banks
+        .iter()
+        .map(|bank| find_max_joltage_for_bank(bank, num_batteries))
+        .sum()
+}
+
+pub struct Solver {}
+
+impl crate::Solver for Solver {
+    const TITLE: &str = "Lobby";
+    type Problem = Box<[Box<[u8]>]>;
+
+    fn parse_input(data: String) -> Result<Self::Problem, Error> {
+        if data.trim().is_empty() {
+            return Err(Error::invalid("Empty input"));
+        }
+
+        data.lines()
+            .map(|line| {
+                let bank = line
+                    .char_indices()
+                    .map(|(index, c)| {
+                        c.to_digit(10).map(|d| d as u8).ok_or_else(|| {
+                            Error::parse_at(
+                                &data,
+                                &line[index..],
+                                format!("Invalid joltage {:?}", c),
+                            )
+                        })
+                    })
+                    .collect::<Result<Box<[u8]>, _>>()?;
+
+                if bank.len() < MAX_BATTERIES {
+                    return Err(Error::parse_at(
+                        &data,
+                        line,
+                        format!("Bank has fewer than {} batteries", MAX_BATTERIES),
+                    ));
+                }
+                Ok(bank)
+            })
+            .collect()
+    }
+
+    fn part_one(banks: &Self::Problem) -> Option<Answer> {
+        Some(find_max_joltage(banks, 2).into())
+    }
+
+    fn part_two(banks: &Self::Problem) -> Option<Answer> {
+        Some(find_max_joltage(banks, MAX_BATTERIES).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Solver as _;
+    use crate::test::parse_error;
+
+    const EXAMPLE: &str = include_str!("../../examples/2025/day03/example.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let banks = Solver::parse_input(EXAMPLE.to_string()).unwrap();
+        assert_eq!(banks.len(), 4);
+        let crlf = Solver::parse_input(EXAMPLE.replace('\n', "\r\n")).unwrap();
+        assert_eq!(crlf, banks);
+
+        assert_eq!(parse_error::<Solver>(""), "Empty input");
+        assert_eq!(
+            parse_error::<Solver>("987654321111111\n81111x111111119\n"),
+            "line 2, column 6: Invalid joltage 'x'\n  81111x111111119\n       ^"
+        );
+        assert!(
+            parse_error::<Solver>("987654321111111\n\n234234234234278\n").starts_with("line 2")
+        );
+        assert!(parse_error::<Solver>("9876543\n").contains("fewer than 12 batteries"));
+    }
+}
@@ -1,14 +1,15 @@
-use crate::common::Position;
-use failure::Error;
+use crate::Answer;
+use crate::Error;
+use crate::common::{Position, SparseGrid};
 use std::collections::HashSet;
 
 pub struct Rolls {
-    rolls: HashSet<Position>,
+    rolls: SparseGrid<()>,
 }
 
 impl Rolls {
     fn adjacent_rolls(&self, pos: Position) -> impl Iterator<Item = Position> {
-        pos.surrounding().filter(|adj| self.rolls.contains(adj))
+        self.rolls.surrounding(pos).map(|(adj, _)| adj)
     }
 
     fn reachable(
@@ -18,7 +19,14 @@ impl Rolls {
         let mut reachable = HashSet::new();
         let mut adjacent_to_reachable = HashSet::new();
 
-        let rolls_to_check = from.unwrap_or(&self.rolls);
+        let all_rolls: HashSet<_>;
+        let rolls_to_check = match from {
+            Some(from) => from,
+            None => {
+                all_rolls = self.rolls.positions().collect();
+                &all_rolls
+            }
+        };
 
         for &pos in rolls_to_check.iter() {
             let adjacent: Vec<_> = self.adjacent_rolls(pos).collect();
@@ -43,7 +51,7 @@ impl Rolls {
 
             *adjacent = Some(new_adjacent);
 
-            self.rolls.retain(|pos| !reachable.contains(pos));
+            self.rolls.retain(|pos, _| !reachable.contains(&pos));
             Some(reachable)
         })
     }
@@ -51,31 +59,18 @@ impl Rolls {
 
 pub struct Solver {}
 
-impl super::Solver for Solver {
+impl crate::Solver for Solver {
+    const TITLE: &str = "Printing Department";
+    const COMBINED: bool = true;
     type Problem = Rolls;
 
     fn parse_input(data: String) -> Result<Self::Problem, Error> {
         Ok(Rolls {
-            rolls: data
-                .lines()
-                .enumerate()
-                .flat_map(|(y, row)| {
-                    row.chars().enumerate().filter_map(move |(x, c)| {
-                        if c == '@' {
-                            Some(Position {
-                                x: x as i64,
-                                y: y as i64,
-                            })
-                        } else {
-                            None
-                        }
-                    })
-                })
-                .collect(),
+            rolls: SparseGrid::parse(&data, |c| (c == '@').then_some(())),
         })
     }
 
-    fn solve(mut rolls: Self::Problem) -> (Option<String>, Option<String>) {
+    fn solve(mut rolls: Self::Problem) -> (Option<Answer>, Option<Answer>) {
         let mut removed_iter = rolls.remove_rolls();
 
         let mut removed = removed_iter.next().unwrap();
@@ -86,6 +81,6 @@ impl super::Solver for Solver {
         }
 
         let part2 = removed.len();
-        (Some(part1.to_string()), Some(part2.to_string()))
+        (Some(part1.into()), Some(part2.into()))
     }
 }
@@ -1,20 +1,19 @@
 use std::{cmp::max, ops::RangeInclusive};
 
-use failure::Error;
+use crate::Answer;
+use crate::Error;
 
 mod parse {
-    use failure::{Error, err_msg};
+    use crate::Error;
     use nom::{
-        IResult, Parser,
-        bytes::complete::tag,
-        character::complete::newline,
-        combinator::{all_consuming, map},
-        multi::many1,
-        sequence::{separated_pair, terminated},
+        IResult, Parser, bytes::complete::tag, character::complete::newline, combinator::map,
+        sequence::separated_pair,
     };
     use std::ops::RangeInclusive;
 
-    use crate::parsers::unsigned;
+    use crate::parsers::{lines1, parse_all, unsigned};
+
+    use super::Database;
 
     fn range(input: &str) -> IResult<&str, RangeInclusive<u64>> {
         map(
@@ -25,23 +24,20 @@ mod parse {
     }
 
     fn ranges(input: &str) -> IResult<&str, Box<[RangeInclusive<u64>]>> {
-        map(many1(terminated(range, newline)), Vec::into_boxed_slice).parse(input)
+        map(lines1(range), Vec::into_boxed_slice).parse(input)
     }
 
     fn ids(input: &str) -> IResult<&str, Box<[u64]>> {
-        map(many1(terminated(unsigned, newline)), Vec::into_boxed_slice).parse(input)
+        map(lines1(unsigned), Vec::into_boxed_slice).parse(input)
     }
 
-    pub(super) fn parse_input(
-        input: &str,
-    ) -> Result<(Box<[RangeInclusive<u64>]>, Box<[u64]>), Error> {
-        all_consuming(separated_pair(ranges, newline, ids))
-            .parse(input)
-            .map(|(_, res)| res)
-            .map_err(|err| err_msg(format!("Failed to parse input: {}", err)))
+    pub(super) fn parse_input(input: &str) -> Result<Database, Error> {
+        parse_all(separated_pair(ranges, newline, ids), input)
     }
 }
 
+type Database = (Box<[RangeInclusive<u64>]>, Box<[u64]>);
+
 struct FreshnessChecker {
     fresh_ranges: Box<[RangeInclusive<u64>]>,
 }
@@ -109,17 +105,21 @@ fn count_fresh_ingredients(checker: &FreshnessChecker, ingredients: &[u64]) -> u
 
 pub struct Solver {}
 
-impl super::Solver for Solver {
-    type Problem = (Box<[RangeInclusive<u64>]>, Box<[u64]>);
+impl crate::Solver for Solver {
+    const TITLE: &str = "Cafeteria";
+    type Problem = Database;
 
     fn parse_input(data: String) -> Result<Self::Problem, Error> {
         parse::parse_input(&data)
     }
 
-    fn solve((fresh_ranges, ingredients): Self::Problem) -> (Option<String>, Option<String>) {
-        let checker = FreshnessChecker::new(&fresh_ranges);
-        let part1 = count_fresh_ingredients(&checker, &ingredients);
-        let part2 = checker.total_fresh_ingredients();
-        (Some(part1.to_string()), Some(part2.to_string()))
+    fn part_one((fresh_ranges, ingredients): &Self::Problem) -> Option<Answer> {
+        let checker = FreshnessChecker::new(fresh_ranges);
+        Some(count_fresh_ingredients(&checker, ingredients).into())
+    }
+
+    fn part_two((fresh_ranges, _): &Self::Problem) -> Option<Answer> {
+        let checker = FreshnessChecker::new(fresh_ranges);
+        Some(checker.total_fresh_ingredients().into())
     }
 }
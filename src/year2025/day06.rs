@@ -0,0 +1,192 @@
+use crate::Answer;
+use crate::Error;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    fn apply(self, nums: impl Iterator<Item = u64>) -> u64 {
+        match self {
+            Operation::Add => nums.sum(),
+            Operation::Multiply => nums.product(),
+        }
+    }
+}
+
+trait NumberParser {
+    fn parse_numbers(digits: &[Vec<Option<u8>>]) -> impl Iterator<Item = u64>;
+}
+
+struct BasicNumberParser {}
+
+impl NumberParser for BasicNumberParser {
+    fn parse_numbers(digits: &[Vec<Option<u8>>]) -> impl Iterator<Item = u64> {
+        digits.iter().map(|row| {
+            row.iter()
+                .rev()
+                .filter_map(|d| *d)
+                .zip(0..)
+                .map(|(d, pos)| d as u64 * 10u64.pow(pos))
+                .sum()
+        })
+    }
+}
+
+struct CorrectNumberParser {}
+
+impl NumberParser for CorrectNumberParser {
+    fn parse_numbers(digits: &[Vec<Option<u8>>]) -> impl Iterator<Item = u64> {
+        (0..digits[0].len()).rev().map(|index| {
+            digits
+                .iter()
+                .rev()
+                .filter_map(|row| row[index])
+                .zip(0..)
+                .map(|(d, pos)| d as u64 * 10u64.pow(pos))
+                .sum()
+        })
+    }
+}
+
+pub struct Problem {
+    operation: Operation,
+    digits: Vec<Vec<Option<u8>>>,
+}
+
+impl Problem {
+    fn solve<N: NumberParser>(&self) -> u64 {
+        self.operation.apply(N::parse_numbers(&self.digits))
+    }
+}
+
+fn calculate_grand_total<N: NumberParser>(problems: &[Problem]) -> u64 {
+    problems.iter().map(|problem| problem.solve::<N>()).sum()
+}
+
+pub struct Solver {}
+
+impl crate::Solver for Solver {
+    const TITLE: &str = "Trash Compactor";
+    type Problem = Vec<Problem>;
+
+    fn parse_input(data: String) -> Result<Self::Problem, Error> {
+        let mut rows: Vec<_> = data.lines().collect();
+        let operation_row = rows.pop().ok_or_else(|| Error::invalid("Empty input"))?;
+        if rows.is_empty() {
+            return Err(Error::parse_at(&data, operation_row, "No rows of numbers"));
+        }
+
+        let width = operation_row.chars().count();
+        for row in rows.iter() {
+            if row.chars().count() != width {
+                return Err(Error::parse_at(
+                    &data,
+                    row,
+                    format!("Row isn't {} characters wide like the others", width),
+                ));
+            }
+        }
+
+        let mut operations = operation_row
+            .char_indices()
+            .filter_map(|(index, c)| match c {
+                '+' => Some(Ok(Operation::Add)),
+                '*' => Some(Ok(Operation::Multiply)),
+                ' ' => None,
+                c => Some(Err(Error::parse_at(
+                    &data,
+                    &operation_row[index..],
+                    format!("Invalid operation {:?}", c),
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        operations.reverse();
+
+        let lines = rows
+            .iter()
+            .map(|row| {
+                row.char_indices()
+                    .map(|(index, c)| match c {
+                        ' ' => Ok(None),
+                        c => c.to_digit(10).map(|d| Some(d as u8)).ok_or_else(|| {
+                            Error::parse_at(&data, &row[index..], format!("Invalid digit {:?}", c))
+                        }),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let too_few_operations =
+            || Error::parse_at(&data, operation_row, "Fewer operations than problems");
+
+        let mut problems = vec![];
+        let mut digits = (0..lines.len()).map(|_| vec![]).collect();
+
+        for index in 0..width {
+            if lines.iter().all(|line| line[index].is_none()) {
+                problems.push(Problem {
+                    operation: operations.pop().ok_or_else(too_few_operations)?,
+                    digits,
+                });
+                digits = (0..lines.len()).map(|_| vec![]).collect()
+            } else {
+                for row in 0..digits.len() {
+                    digits[row].push(lines[row][index]);
+                }
+            }
+        }
+
+        problems.push(Problem {
+            operation: operations.pop().ok_or_else(too_few_operations)?,
+            digits,
+        });
+
+        if !operations.is_empty() {
+            return Err(Error::parse_at(
+                &data,
+                operation_row,
+                "More operations than problems",
+            ));
+        }
+
+        Ok(problems)
+    }
+
+    fn part_one(problems: &Self::Problem) -> Option<Answer> {
+        Some(calculate_grand_total::<BasicNumberParser>(problems).into())
+    }
+
+    fn part_two(problems: &Self::Problem) -> Option<Answer> {
+        Some(calculate_grand_total::<CorrectNumberParser>(problems).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Solver as _;
+    use crate::test::parse_error;
+
+    const EXAMPLE: &str = include_str!("../../examples/2025/day06/example.txt");
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(Solver::parse_input(EXAMPLE.to_string()).unwrap().len(), 4);
+        let crlf = Solver::parse_input(EXAMPLE.replace('\n', "\r\n")).unwrap();
+        assert_eq!(Solver::part_two(&crlf), Some(3263827.into()));
+
+        assert_eq!(parse_error::<Solver>(""), "Empty input");
+        assert!(parse_error::<Solver>("*   +\n").contains("No rows of numbers"));
+        assert!(parse_error::<Solver>("12 3\n4 5\n*  +\n").contains("isn't 4 characters wide"));
+        assert_eq!(
+            parse_error::<Solver>("12 3\n4x 5\n*  +\n"),
+            "line 2, column 2: Invalid digit 'x'\n  4x 5\n   ^"
+        );
+        assert!(parse_error::<Solver>("12 3\n45 6\n*  -\n").contains("Invalid operation '-'"));
+        assert!(parse_error::<Solver>("12 3\n45 6\n*   \n").contains("Fewer operations"));
+        assert!(parse_error::<Solver>("12 3\n45 6\n*+ +\n").contains("More operations"));
+    }
+}
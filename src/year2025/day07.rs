@@ -0,0 +1,116 @@
+use crate::Answer;
+use crate::Error;
+use crate::common::{Counter, Grid, Position};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Splitter,
+    Start,
+}
+
+pub struct Manifold {
+    start: Position,
+    splitters: Grid<bool>,
+}
+
+impl Manifold {
+    fn total_splits(&self) -> (usize, usize) {
+        let mut splits = 0;
+        let start_row = self.start.y as usize;
+        let mut beams: Counter<usize> = [(self.start.x as usize, 1)].into_iter().collect();
+
+        for row in start_row..self.splitters.height() {
+            let splitters = self.splitters.row(row);
+            let (split, not_split): (HashMap<_, _>, HashMap<_, _>) =
+                beams.into_iter().partition(|(beam, _)| splitters[*beam]);
+            splits += split.len();
+            beams = split
+                .into_iter()
+                .flat_map(|(beam, count)| [(beam - 1, count), (beam + 1, count)])
+                .chain(not_split)
+                .collect();
+        }
+
+        (splits, beams.iter().map(|(_, &count)| count).sum())
+    }
+}
+
+pub struct Solver {}
+
+impl crate::Solver for Solver {
+    const TITLE: &str = "Laboratories";
+    const COMBINED: bool = true;
+    type Problem = Manifold;
+
+    fn parse_input(data: String) -> Result<Self::Problem, Error> {
+        let grid = Grid::parse(&data, |c| match c {
+            '.' => Some(Cell::Empty),
+            '^' => Some(Cell::Splitter),
+            'S' => Some(Cell::Start),
+            _ => None,
+        })?;
+
+        // Splitting sends beams either side, which must stay on the grid.
+        let last = grid.width() as i64 - 1;
+        if let Some((pos, _)) = grid
+            .iter()
+            .find(|&(pos, &cell)| cell == Cell::Splitter && (pos.x == 0 || pos.x == last))
+        {
+            return Err(Error::invalid(format!(
+                "Splitter on the edge of the grid at {}",
+                pos
+            )));
+        }
+
+        let mut starts = grid.iter().filter(|&(_, &cell)| cell == Cell::Start);
+        let (start, _) = starts
+            .next()
+            .ok_or_else(|| Error::invalid("Failed to find start position"))?;
+        if let Some((pos, _)) = starts.next() {
+            return Err(Error::invalid(format!(
+                "More than one start position, at {} and {}",
+                start, pos
+            )));
+        }
+
+        let splitters = grid.map(|&cell| cell == Cell::Splitter);
+        Ok(Manifold { start, splitters })
+    }
+
+    fn solve(manifold: Self::Problem) -> (Option<Answer>, Option<Answer>) {
+        let (part1, part2) = manifold.total_splits();
+        (Some(part1.into()), Some(part2.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Solver as _;
+    use crate::test::parse_error;
+
+    const EXAMPLE: &str = include_str!("../../examples/2025/day07/example.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let manifold = Solver::parse_input(EXAMPLE.to_string()).unwrap();
+        assert_eq!(manifold.start, Position { x: 7, y: 0 });
+        let crlf = Solver::parse_input(EXAMPLE.replace('\n', "\r\n")).unwrap();
+        assert_eq!(crlf.splitters, manifold.splitters);
+
+        assert_eq!(parse_error::<Solver>(""), "Empty input");
+        assert_eq!(
+            parse_error::<Solver>("...\n.^.\n"),
+            "Failed to find start position"
+        );
+        assert!(parse_error::<Solver>(".S.\n.^\n").contains("isn't 3 characters wide"));
+        assert!(parse_error::<Solver>(".S.\n^..\n").contains("Splitter on the edge"));
+        assert!(parse_error::<Solver>(".S.\n.S.\n").contains("More than one start"));
+        assert_eq!(
+            parse_error::<Solver>(".S.\n.x.\n"),
+            "line 2, column 2: Invalid cell 'x'\n  .x.\n   ^"
+        );
+    }
+}
@@ -1,8 +1,10 @@
-use failure::{Error, err_msg};
+use crate::Answer;
+use crate::Error;
+use crate::common::Point3;
 use itertools::Itertools;
 use std::cmp::Reverse;
 
-fn make_connections(boxes: &[(i64, i64, i64)]) -> impl Iterator<Item = (usize, usize)> {
+fn make_connections(boxes: &[Point3<i64>]) -> impl Iterator<Item = (usize, usize)> {
     boxes
         .iter()
         .enumerate()
@@ -11,9 +13,7 @@ fn make_connections(boxes: &[(i64, i64, i64)]) -> impl Iterator<Item = (usize, u
                 .zip(boxes[box_a + 1..].iter())
                 .map(move |(box_b, pos_b)| ((box_a, pos_a), (box_b, pos_b)))
         })
-        .sorted_by_cached_key(|((_, (x_a, y_a, z_a)), (_, (x_b, y_b, z_b)))| {
-            (x_a - x_b).pow(2) + (y_a - y_b).pow(2) + (z_a - z_b).pow(2)
-        })
+        .sorted_by_cached_key(|((_, pos_a), (_, pos_b))| pos_a.squared_distance(**pos_b))
         .map(|((box_a, _), (box_b, _))| (box_a, box_b))
 }
 
@@ -85,10 +85,40 @@ impl Circuits {
             == self.box_circuits.len()
     }
 }
+
+/// The number of connections made before part one is answered.
+const CONNECTIONS: usize = 1000;
+
+fn solve_with(boxes: &[Point3<i64>], connections: usize) -> (Option<Answer>, Option<Answer>) {
+    let mut circuits = Circuits::new(boxes.len());
+    let mut pairs = make_connections(boxes);
+
+    for _ in 0..connections {
+        let (box_a, box_b) = pairs.next().unwrap();
+        circuits.connect(box_a, box_b);
+    }
+
+    let circuit_sizes = circuits.circuit_sizes();
+    let part1: usize = (0..3).map(|idx| circuit_sizes[idx]).product();
+
+    let mut part2 = None;
+    for (box_a, box_b) in pairs {
+        circuits.connect(box_a, box_b);
+        if circuits.is_fully_merged() {
+            part2 = Some((boxes[box_a].x * boxes[box_b].x).into());
+            break;
+        }
+    }
+
+    (Some(part1.into()), part2)
+}
+
 pub struct Solver {}
 
-impl super::Solver for Solver {
-    type Problem = Box<[(i64, i64, i64)]>;
+impl crate::Solver for Solver {
+    const TITLE: &str = "Playground";
+    const COMBINED: bool = true;
+    type Problem = Box<[Point3<i64>]>;
 
     fn parse_input(data: String) -> Result<Self::Problem, Error> {
         data.lines()
@@ -97,41 +127,52 @@ impl super::Solver for Solver {
                 let mut get_coordinate = |coord: &str| -> Result<i64, Error> {
                     parts
                         .next()
-                        .ok_or_else(|| err_msg(format!("No {} coordinate", coord)))?
-                        .parse()
-                        .map_err(|err| err_msg(format!("Invalid {} coordinate: {}", coord, err)))
+                        .ok_or_else(|| {
+                            Error::parse_at(&data, line, format!("No {} coordinate", coord))
+                        })
+                        .and_then(|part| {
+                            part.parse().map_err(|err| {
+                                Error::parse_at(
+                                    &data,
+                                    part,
+                                    format!("Invalid {} coordinate: {}", coord, err),
+                                )
+                            })
+                        })
                 };
                 let x = get_coordinate("x")?;
                 let y = get_coordinate("y")?;
                 let z = get_coordinate("z")?;
 
-                Ok((x, y, z))
+                Ok(Point3 { x, y, z })
             })
             .collect::<Result<Vec<_>, _>>()
             .map(Vec::into_boxed_slice)
     }
 
-    fn solve(boxes: Self::Problem) -> (Option<String>, Option<String>) {
-        let mut circuits = Circuits::new(boxes.len());
-        let mut connections = make_connections(&boxes);
-
-        for _ in 0..1000 {
-            let (box_a, box_b) = connections.next().unwrap();
-            circuits.connect(box_a, box_b);
-        }
+    fn solve(boxes: Self::Problem) -> (Option<Answer>, Option<Answer>) {
+        solve_with(&boxes, CONNECTIONS)
+    }
+}
 
-        let circuit_sizes = circuits.circuit_sizes();
-        let part1: usize = (0..3).map(|idx| circuit_sizes[idx]).product();
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Solver as _;
 
-        let mut part2 = None;
-        while let Some((box_a, box_b)) = connections.next() {
-            circuits.connect(box_a, box_b);
-            if circuits.is_fully_merged() {
-                part2 = Some((boxes[box_a].0 * boxes[box_b].0).to_string());
-                break;
-            }
-        }
+    // The puzzle's example only makes 10 connections, so it can't be one of
+    // the fixtures under examples/.
+    const EXAMPLE: &str = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n\
+        466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n\
+        819,987,18\n117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n\
+        862,61,35\n984,92,344\n425,690,689\n";
 
-        (Some(part1.to_string()), part2)
+    #[test]
+    fn test_example() {
+        let boxes = Solver::parse_input(EXAMPLE.to_string()).unwrap();
+        assert_eq!(
+            solve_with(&boxes, 10),
+            (Some(40_usize.into()), Some(25272_i64.into()))
+        );
     }
 }
@@ -1,5 +1,6 @@
+use crate::Answer;
+use crate::Error;
 use crate::common::{Direction, Position};
-use failure::{Error, err_msg};
 use itertools::Itertools;
 use std::{
     cmp::{max, min},
@@ -7,8 +8,9 @@ use std::{
 };
 
 fn get_sector(pos_a: Position, pos_b: Position, pos_c: Position, clockwise: bool) -> Sector {
-    let d1 = pos_b.direction_to(pos_c);
-    let d2 = pos_b.direction_to(pos_a);
+    // Consecutive tiles are distinct, or the cross products would be zero.
+    let d1 = pos_b.direction_to(pos_c).unwrap();
+    let d2 = pos_b.direction_to(pos_a).unwrap();
 
     if clockwise {
         Sector::new(d1, d2)
@@ -105,7 +107,10 @@ impl InternalChecker {
         let tile_a = self.tiles[tile_a_idx];
         let tile_b = self.tiles[tile_b_idx];
 
-        let direction = tile_a.direction_to(tile_b);
+        let Some(direction) = tile_a.direction_to(tile_b) else {
+            // A single tile is always inside.
+            return true;
+        };
         if !self.sectors[tile_a_idx].contains(direction) {
             return false;
         }
@@ -188,7 +193,8 @@ where
 
 pub struct Solver {}
 
-impl super::Solver for Solver {
+impl crate::Solver for Solver {
+    const TITLE: &str = "Movie Theater";
     type Problem = Box<[Position]>;
 
     fn parse_input(data: String) -> Result<Self::Problem, Error> {
@@ -196,22 +202,28 @@ impl super::Solver for Solver {
             .map(|line| {
                 let (x_str, y_str) = line
                     .split_once(",")
-                    .ok_or_else(|| err_msg(format!("Invalid position: {}", line)))?;
-                let x = x_str.parse()?;
-                let y = y_str.parse()?;
+                    .ok_or_else(|| Error::parse_at(&data, line, "Invalid position"))?;
+                let parse_coordinate = |coord: &str| {
+                    coord.parse().map_err(|err| {
+                        Error::parse_at(&data, coord, format!("Invalid coordinate: {}", err))
+                    })
+                };
+                let x = parse_coordinate(x_str)?;
+                let y = parse_coordinate(y_str)?;
                 Ok(Position { x, y })
             })
             .collect()
     }
 
-    fn solve(tiles: Self::Problem) -> (Option<String>, Option<String>) {
-        let part1 = find_max_area(&tiles, |_, _| true);
+    fn part_one(tiles: &Self::Problem) -> Option<Answer> {
+        Some(find_max_area(tiles, |_, _| true).into())
+    }
 
-        let internal_checker = InternalChecker::new(&tiles);
-        let part2 = find_max_area(&tiles, |pos_a, pos_b| {
+    fn part_two(tiles: &Self::Problem) -> Option<Answer> {
+        let internal_checker = InternalChecker::new(tiles);
+        let area = find_max_area(tiles, |pos_a, pos_b| {
             internal_checker.is_internal(pos_a, pos_b)
         });
-
-        (Some(part1.to_string()), Some(part2.to_string()))
+        Some(area.into())
     }
 }
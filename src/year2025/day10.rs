@@ -1,27 +1,21 @@
-use std::usize;
-
-use failure::Error;
+use crate::Answer;
+use crate::Error;
 use parse::parse_input;
 
 mod parse {
-    use crate::parsers::unsigned;
-    use failure::{Error, err_msg};
+    use crate::Error;
+    use crate::parsers::{lines1, parse_all, unsigned};
     use nom::branch::alt;
     use nom::bytes::complete::tag;
-    use nom::character::complete::newline;
-    use nom::combinator::{all_consuming, map, value};
+    use nom::combinator::{map, value};
     use nom::multi::{many1, separated_list1};
-    use nom::sequence::{delimited, terminated};
+    use nom::sequence::delimited;
     use nom::{IResult, Parser};
 
-    use super::{Machine, Light};
-
+    use super::{Light, Machine};
 
     fn light(input: &str) -> IResult<&str, Light> {
-        alt((
-            value(Light::Off, tag(".")),
-            value(Light::On, tag("#"))
-        )).parse(input)
+        alt((value(Light::Off, tag(".")), value(Light::On, tag("#")))).parse(input)
     }
 
     fn lights(input: &str) -> IResult<&str, Box<[Light]>> {
@@ -29,45 +23,55 @@ mod parse {
     }
 
     fn button(input: &str) -> IResult<&str, Box<[usize]>> {
-        delimited(tag("("), map(separated_list1(tag(","), unsigned), Vec::into_boxed_slice), tag(")")).parse(input)
+        delimited(
+            tag("("),
+            map(separated_list1(tag(","), unsigned), Vec::into_boxed_slice),
+            tag(")"),
+        )
+        .parse(input)
     }
 
-
     fn buttons(input: &str) -> IResult<&str, Box<[Box<[usize]>]>> {
         map(separated_list1(tag(" "), button), Vec::into_boxed_slice).parse(input)
     }
 
     fn joltage(input: &str) -> IResult<&str, Box<[u64]>> {
-        delimited(tag("{"), map(separated_list1(tag(","), unsigned), Vec::into_boxed_slice), tag("}")).parse(input)
+        delimited(
+            tag("{"),
+            map(separated_list1(tag(","), unsigned), Vec::into_boxed_slice),
+            tag("}"),
+        )
+        .parse(input)
     }
 
     fn machine(input: &str) -> IResult<&str, Machine> {
-        map((lights, tag(" "), buttons, tag(" "), joltage), |(lights, _, buttons, _, _joltage)| Machine { lights, buttons, _joltage }).parse(input)
+        map(
+            (lights, tag(" "), buttons, tag(" "), joltage),
+            |(lights, _, buttons, _, _joltage)| Machine {
+                lights,
+                buttons,
+                _joltage,
+            },
+        )
+        .parse(input)
     }
 
-
     pub(super) fn parse_input(s: &str) -> Result<Box<[Machine]>, Error> {
-        all_consuming(map(
-            many1(terminated(machine, newline)),
-            Vec::into_boxed_slice,
-        ))
-        .parse(s)
-        .map_err(|err| err_msg(format!("Failed to parse input: {}", err)))
-        .map(|(_, machines)| machines)
+        parse_all(map(lines1(machine), Vec::into_boxed_slice), s)
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Light {
     On,
-    Off
+    Off,
 }
 
 impl Light {
     fn flip(&mut self) {
         *self = match self {
             Light::On => Light::Off,
-            Light::Off => Light::On
+            Light::Off => Light::On,
         }
     }
 }
@@ -79,7 +83,6 @@ pub struct Machine {
 }
 
 impl Machine {
-
     fn press_button(&self, lights: &[Light], buttons: &[usize]) -> Vec<Light> {
         let mut lights = lights.to_vec();
         for &index in buttons {
@@ -89,18 +92,26 @@ impl Machine {
     }
 
     fn num_matching_lights(&self, lights: &[Light]) -> usize {
-        self.lights.iter().zip(lights.iter()).filter(|(l1, l2)| l1 == l2).count()
+        self.lights
+            .iter()
+            .zip(lights.iter())
+            .filter(|(l1, l2)| l1 == l2)
+            .count()
     }
 
     fn min_presses(&self) -> usize {
         let mut best = usize::MAX;
-        let mut stack = vec![(self.lights.iter().map(|_| Light::Off).collect::<Vec<_>>(), &self.buttons[..], 0)];
+        let mut stack = vec![(
+            self.lights.iter().map(|_| Light::Off).collect::<Vec<_>>(),
+            &self.buttons[..],
+            0,
+        )];
 
         while let Some((lights, buttons, count)) = stack.pop() {
             if count >= best {
                 continue;
             }
-            if &lights[..] == &self.lights[..] {
+            if lights[..] == self.lights[..] {
                 best = count;
                 continue;
             }
@@ -131,15 +142,20 @@ impl Machine {
 
 pub struct Solver {}
 
-impl super::Solver for Solver {
+impl crate::Solver for Solver {
+    const TITLE: &str = "Factory";
     type Problem = Box<[Machine]>;
 
     fn parse_input(data: String) -> Result<Self::Problem, Error> {
         parse_input(&data)
     }
 
-    fn solve(machines: Self::Problem) -> (Option<String>, Option<String>) {
-        let part1: usize = machines.iter().map(|machine| machine.min_presses()).sum();
-        (Some(part1.to_string()), None)
+    fn part_one(machines: &Self::Problem) -> Option<Answer> {
+        let presses: usize = machines.iter().map(|machine| machine.min_presses()).sum();
+        Some(presses.into())
+    }
+
+    fn part_two(_machines: &Self::Problem) -> Option<Answer> {
+        None
     }
 }
@@ -0,0 +1,36 @@
+use crate::{Answer, Error};
+use parse::parse_input;
+
+mod parse {
+    use crate::Error;
+    use crate::parsers::{lines1, parse_all, unsigned};
+    use nom::IResult;
+    use nom::combinator::map;
+
+    fn line(s: &str) -> IResult<&str, u64> {
+        unsigned(s)
+    }
+
+    pub(super) fn parse_input(s: &str) -> Result<Box<[u64]>, Error> {
+        parse_all(map(lines1(line), Vec::into_boxed_slice), s)
+    }
+}
+
+pub struct Solver {}
+
+impl crate::Solver for Solver {
+    const TITLE: &str = "Day {{day}}";
+    type Problem = Box<[u64]>;
+
+    fn parse_input(data: String) -> Result<Self::Problem, Error> {
+        parse_input(&data)
+    }
+
+    fn part_one(_lines: &Self::Problem) -> Option<Answer> {
+        None
+    }
+
+    fn part_two(_lines: &Self::Problem) -> Option<Answer> {
+        None
+    }
+}